@@ -4,6 +4,7 @@ use core::net::{
 };
 
 use crate::{
+	fixed_bit_string::OffsetOverflow,
 	utils::bigendian::int_helpers::{
 		u128,
 		u32,
@@ -82,6 +83,76 @@ impl FixedBitString for Ipv4Addr {
 	fn contains(&self, prefix: usize, other: &Self) -> bool {
 		u32::element_contains(self.to_bits(), prefix, other.to_bits())
 	}
+
+	fn add(&mut self, prefix: usize, n: core::primitive::u128) -> bool {
+		with_ipv4_mut_u32(self, |num| u32::element_add(num, prefix, n))
+	}
+
+	fn sub(&mut self, prefix: usize, n: core::primitive::u128) -> bool {
+		with_ipv4_mut_u32(self, |num| u32::element_sub(num, prefix, n))
+	}
+
+	fn distance(
+		&self,
+		prefix: usize,
+		other: &Self,
+	) -> Result<core::primitive::u128, OffsetOverflow> {
+		assert!(prefix <= Self::LEN);
+		Ok(u32::element_distance(self.to_bits(), prefix, other.to_bits()).into())
+	}
+
+	fn count_ones(&self) -> usize {
+		u32::element_count_ones(self.to_bits())
+	}
+
+	fn leading_zeros_from(&self, ndx: usize) -> usize {
+		u32::element_leading_zeros_from(self.to_bits(), ndx)
+	}
+
+	fn leading_ones_from(&self, ndx: usize) -> usize {
+		u32::element_leading_ones_from(self.to_bits(), ndx)
+	}
+
+	fn find_next_set(&self, ndx: usize) -> Option<usize> {
+		u32::element_find_next_set(self.to_bits(), ndx)
+	}
+
+	fn find_next_clear(&self, ndx: usize) -> Option<usize> {
+		u32::element_find_next_clear(self.to_bits(), ndx)
+	}
+
+	fn and_with(&mut self, other: &Self) {
+		*self = Ipv4Addr::from_bits(self.to_bits() & other.to_bits());
+	}
+
+	fn or_with(&mut self, other: &Self) {
+		*self = Ipv4Addr::from_bits(self.to_bits() | other.to_bits());
+	}
+
+	fn xor_with(&mut self, other: &Self) {
+		*self = Ipv4Addr::from_bits(self.to_bits() ^ other.to_bits());
+	}
+
+	fn invert(&mut self) {
+		*self = Ipv4Addr::from_bits(!self.to_bits());
+	}
+
+	fn offset_in(&self, prefix: usize) -> Result<core::primitive::u128, OffsetOverflow> {
+		assert!(prefix <= Self::LEN);
+		Ok(u32::element_offset_in(self.to_bits(), prefix).into())
+	}
+
+	fn with_offset(
+		self,
+		prefix: usize,
+		offset: core::primitive::u128,
+	) -> Result<Self, OffsetOverflow> {
+		assert!(prefix <= Self::LEN);
+		let offset = offset.try_into().map_err(|_| OffsetOverflow)?;
+		let bits =
+			u32::make_element_with_offset(self.to_bits(), prefix, offset).ok_or(OffsetOverflow)?;
+		Ok(Ipv4Addr::from_bits(bits))
+	}
 }
 
 fn with_ipv6_mut_slice<F, T>(addr: &mut Ipv6Addr, f: F) -> T
@@ -154,4 +225,77 @@ impl FixedBitString for Ipv6Addr {
 	fn contains(&self, prefix: usize, other: &Self) -> bool {
 		u128::element_contains(self.to_bits(), prefix, other.to_bits())
 	}
+
+	fn add(&mut self, prefix: usize, n: core::primitive::u128) -> bool {
+		with_ipv6_mut_u128(self, |num| u128::element_add(num, prefix, n))
+	}
+
+	fn sub(&mut self, prefix: usize, n: core::primitive::u128) -> bool {
+		with_ipv6_mut_u128(self, |num| u128::element_sub(num, prefix, n))
+	}
+
+	fn distance(
+		&self,
+		prefix: usize,
+		other: &Self,
+	) -> Result<core::primitive::u128, OffsetOverflow> {
+		assert!(prefix <= Self::LEN);
+		Ok(u128::element_distance(
+			self.to_bits(),
+			prefix,
+			other.to_bits(),
+		))
+	}
+
+	fn count_ones(&self) -> usize {
+		u128::element_count_ones(self.to_bits())
+	}
+
+	fn leading_zeros_from(&self, ndx: usize) -> usize {
+		u128::element_leading_zeros_from(self.to_bits(), ndx)
+	}
+
+	fn leading_ones_from(&self, ndx: usize) -> usize {
+		u128::element_leading_ones_from(self.to_bits(), ndx)
+	}
+
+	fn find_next_set(&self, ndx: usize) -> Option<usize> {
+		u128::element_find_next_set(self.to_bits(), ndx)
+	}
+
+	fn find_next_clear(&self, ndx: usize) -> Option<usize> {
+		u128::element_find_next_clear(self.to_bits(), ndx)
+	}
+
+	fn and_with(&mut self, other: &Self) {
+		*self = Ipv6Addr::from_bits(self.to_bits() & other.to_bits());
+	}
+
+	fn or_with(&mut self, other: &Self) {
+		*self = Ipv6Addr::from_bits(self.to_bits() | other.to_bits());
+	}
+
+	fn xor_with(&mut self, other: &Self) {
+		*self = Ipv6Addr::from_bits(self.to_bits() ^ other.to_bits());
+	}
+
+	fn invert(&mut self) {
+		*self = Ipv6Addr::from_bits(!self.to_bits());
+	}
+
+	fn offset_in(&self, prefix: usize) -> Result<core::primitive::u128, OffsetOverflow> {
+		assert!(prefix <= Self::LEN);
+		Ok(u128::element_offset_in(self.to_bits(), prefix))
+	}
+
+	fn with_offset(
+		self,
+		prefix: usize,
+		offset: core::primitive::u128,
+	) -> Result<Self, OffsetOverflow> {
+		assert!(prefix <= Self::LEN);
+		let bits =
+			u128::make_element_with_offset(self.to_bits(), prefix, offset).ok_or(OffsetOverflow)?;
+		Ok(Ipv6Addr::from_bits(bits))
+	}
 }
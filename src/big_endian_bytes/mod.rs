@@ -0,0 +1,371 @@
+#[cfg(feature = "net")]
+use core::net::{
+	Ipv4Addr,
+	Ipv6Addr,
+};
+use core::ops::{
+	BitAnd,
+	BitAndAssign,
+	BitOr,
+	BitOrAssign,
+	BitXor,
+	BitXorAssign,
+	Not,
+};
+
+#[cfg(feature = "bigendian")]
+use crate::utils::BigEndianBitString;
+use crate::{
+	utils::bigendian::int_helpers::u8,
+	FixedBitString,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// Big-endian bit string stored in `N` bytes, i.e. with `8 * N` bits.
+///
+/// The type is a `#[repr(transparent)]` wrapper around `[u8; N]` with
+/// alignment 1, so bytes in a buffer can be used as [`FixedBitString`]
+/// without copying them: [`from_ref`] and [`from_slice`] borrow bytes
+/// read-only, [`from_mut`] and [`from_slice_mut`] borrow them to modify
+/// them in place.
+///
+/// With the `zerocopy` or `bytemuck` feature it implements the traits
+/// needed to embed it in header structs parsed directly from a buffer
+/// (`FromBytes`, `IntoBytes`, `KnownLayout`, `Immutable` and `Unaligned`
+/// of `zerocopy`; `Zeroable` and `Pod` of `bytemuck`).
+///
+/// [`from_ref`]: BigEndianBytes::from_ref
+/// [`from_slice`]: BigEndianBytes::from_slice
+/// [`from_mut`]: BigEndianBytes::from_mut
+/// [`from_slice_mut`]: BigEndianBytes::from_slice_mut
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+	feature = "zerocopy",
+	derive(
+		zerocopy::FromBytes,
+		zerocopy::IntoBytes,
+		zerocopy::KnownLayout,
+		zerocopy::Immutable,
+		zerocopy::Unaligned
+	)
+)]
+#[repr(transparent)]
+pub struct BigEndianBytes<const N: usize>(pub [u8; N]);
+
+impl<const N: usize> BigEndianBytes<N> {
+	/// Wrap bytes
+	pub const fn new(bytes: [u8; N]) -> Self {
+		BigEndianBytes(bytes)
+	}
+
+	/// Borrow bytes as bit string
+	pub const fn from_ref(bytes: &[u8; N]) -> &Self {
+		// SAFETY: `BigEndianBytes<N>` is a `repr(transparent)` wrapper of
+		// `[u8; N]`
+		unsafe { &*(bytes as *const [u8; N] as *const Self) }
+	}
+
+	/// Mutably borrow bytes as bit string
+	pub fn from_mut(bytes: &mut [u8; N]) -> &mut Self {
+		// SAFETY: `BigEndianBytes<N>` is a `repr(transparent)` wrapper of
+		// `[u8; N]`
+		unsafe { &mut *(bytes as *mut [u8; N] as *mut Self) }
+	}
+
+	/// Borrow bytes as bit string; `None` unless `bytes` has exactly `N`
+	/// bytes
+	pub fn from_slice(bytes: &[u8]) -> Option<&Self> {
+		Some(Self::from_ref(bytes.try_into().ok()?))
+	}
+
+	/// Mutably borrow bytes as bit string; `None` unless `bytes` has
+	/// exactly `N` bytes
+	pub fn from_slice_mut(bytes: &mut [u8]) -> Option<&mut Self> {
+		Some(Self::from_mut(bytes.try_into().ok()?))
+	}
+
+	/// Underlying bytes
+	pub const fn as_bytes(&self) -> &[u8; N] {
+		&self.0
+	}
+
+	/// Mutable access to the underlying bytes
+	pub fn as_bytes_mut(&mut self) -> &mut [u8; N] {
+		&mut self.0
+	}
+
+	/// Unwrap bytes
+	pub const fn into_bytes(self) -> [u8; N] {
+		self.0
+	}
+}
+
+impl<const N: usize> Default for BigEndianBytes<N> {
+	fn default() -> Self {
+		BigEndianBytes([0; N])
+	}
+}
+
+impl<const N: usize> From<[u8; N]> for BigEndianBytes<N> {
+	fn from(bytes: [u8; N]) -> Self {
+		BigEndianBytes(bytes)
+	}
+}
+
+impl<const N: usize> From<BigEndianBytes<N>> for [u8; N] {
+	fn from(bytes: BigEndianBytes<N>) -> Self {
+		bytes.0
+	}
+}
+
+#[cfg(feature = "net")]
+impl From<Ipv4Addr> for BigEndianBytes<4> {
+	fn from(addr: Ipv4Addr) -> Self {
+		BigEndianBytes(addr.octets())
+	}
+}
+
+#[cfg(feature = "net")]
+impl From<BigEndianBytes<4>> for Ipv4Addr {
+	fn from(bytes: BigEndianBytes<4>) -> Self {
+		Ipv4Addr::from(bytes.0)
+	}
+}
+
+#[cfg(feature = "net")]
+impl From<Ipv6Addr> for BigEndianBytes<16> {
+	fn from(addr: Ipv6Addr) -> Self {
+		BigEndianBytes(addr.octets())
+	}
+}
+
+#[cfg(feature = "net")]
+impl From<BigEndianBytes<16>> for Ipv6Addr {
+	fn from(bytes: BigEndianBytes<16>) -> Self {
+		Ipv6Addr::from(bytes.0)
+	}
+}
+
+macro_rules! impl_bit_op {
+	($op:ident, $op_fn:ident, $assign:ident, $assign_fn:ident) => {
+		impl<const N: usize> $op for BigEndianBytes<N> {
+			type Output = Self;
+
+			fn $op_fn(mut self, rhs: Self) -> Self {
+				$assign::$assign_fn(&mut self, rhs);
+				self
+			}
+		}
+
+		impl<const N: usize> $assign for BigEndianBytes<N> {
+			fn $assign_fn(&mut self, rhs: Self) {
+				for (a, b) in self.0.iter_mut().zip(rhs.0) {
+					$assign::$assign_fn(a, b);
+				}
+			}
+		}
+	};
+}
+
+impl_bit_op!(BitAnd, bitand, BitAndAssign, bitand_assign);
+impl_bit_op!(BitOr, bitor, BitOrAssign, bitor_assign);
+impl_bit_op!(BitXor, bitxor, BitXorAssign, bitxor_assign);
+
+impl<const N: usize> Not for BigEndianBytes<N> {
+	type Output = Self;
+
+	fn not(self) -> Self {
+		BigEndianBytes(self.0.map(|b| !b))
+	}
+}
+
+// SAFETY: `repr(transparent)` wrapper of `[u8; N]`, which is `Zeroable`
+#[cfg(feature = "bytemuck")]
+unsafe impl<const N: usize> bytemuck::Zeroable for BigEndianBytes<N> {}
+
+// SAFETY: `repr(transparent)` wrapper of `[u8; N]`, which is `Pod`
+#[cfg(feature = "bytemuck")]
+unsafe impl<const N: usize> bytemuck::Pod for BigEndianBytes<N> {}
+
+impl<const N: usize> FixedBitString for BigEndianBytes<N> {
+	const LEN: usize = 8 * N;
+
+	fn inc(&mut self, prefix: usize) -> bool {
+		assert!(prefix <= Self::LEN);
+		u8::slice_inc(&mut self.0, prefix)
+	}
+
+	fn get(&self, ndx: usize) -> bool {
+		u8::slice_get(&self.0, ndx)
+	}
+
+	fn set(&mut self, ndx: usize, bit: bool) {
+		u8::slice_set(&mut self.0, ndx, bit)
+	}
+
+	fn flip(&mut self, ndx: usize) {
+		u8::slice_flip(&mut self.0, ndx)
+	}
+
+	fn shared_prefix_len(&self, other: &Self) -> usize {
+		u8::slice_shared_prefix_len(&self.0, &other.0, Self::LEN)
+	}
+
+	fn set_false_from(&mut self, ndx: usize) {
+		u8::slice_set_false_from(&mut self.0, ndx)
+	}
+
+	fn is_false_from(&self, ndx: usize) -> bool {
+		u8::slice_is_false_from(&self.0, ndx)
+	}
+
+	fn set_true_from(&mut self, ndx: usize) {
+		u8::slice_set_true_from(&mut self.0, ndx)
+	}
+
+	fn is_true_from(&self, ndx: usize) -> bool {
+		u8::slice_is_true_from(&self.0, ndx)
+	}
+
+	fn new_all_false() -> Self {
+		BigEndianBytes([0; N])
+	}
+
+	fn new_all_true() -> Self {
+		BigEndianBytes([!0; N])
+	}
+
+	fn contains(&self, prefix: usize, other: &Self) -> bool {
+		assert!(prefix <= Self::LEN);
+		u8::slice_contains(&self.0, prefix, &other.0)
+	}
+
+	fn count_ones(&self) -> usize {
+		u8::slice_count_ones(&self.0)
+	}
+
+	fn leading_zeros_from(&self, ndx: usize) -> usize {
+		u8::slice_leading_zeros_from(&self.0, ndx)
+	}
+
+	fn leading_ones_from(&self, ndx: usize) -> usize {
+		u8::slice_leading_ones_from(&self.0, ndx)
+	}
+
+	fn find_next_set(&self, ndx: usize) -> Option<usize> {
+		u8::slice_find_next_set(&self.0, ndx)
+	}
+
+	fn find_next_clear(&self, ndx: usize) -> Option<usize> {
+		u8::slice_find_next_clear(&self.0, ndx)
+	}
+
+	fn and_with(&mut self, other: &Self) {
+		*self &= *other;
+	}
+
+	fn or_with(&mut self, other: &Self) {
+		*self |= *other;
+	}
+
+	fn xor_with(&mut self, other: &Self) {
+		*self ^= *other;
+	}
+
+	fn invert(&mut self) {
+		*self = !*self;
+	}
+}
+
+#[cfg(feature = "bigendian")]
+impl<const N: usize> BigEndianBitString for BigEndianBytes<N> {
+	fn bits(&self) -> usize {
+		Self::LEN
+	}
+
+	fn bits_inc(&mut self, prefix: usize) -> bool {
+		self.0.bits_inc(prefix)
+	}
+
+	fn bit_get(&self, ndx: usize) -> bool {
+		self.0.bit_get(ndx)
+	}
+
+	fn bit_set(&mut self, ndx: usize, bit: bool) {
+		self.0.bit_set(ndx, bit)
+	}
+
+	fn bit_flip(&mut self, ndx: usize) {
+		self.0.bit_flip(ndx)
+	}
+
+	fn shared_prefix_len(&self, other: &Self, max_len: usize) -> usize {
+		BigEndianBitString::shared_prefix_len(&self.0[..], &other.0[..], max_len)
+	}
+
+	fn set_false_from(&mut self, ndx: usize) {
+		BigEndianBitString::set_false_from(&mut self.0[..], ndx)
+	}
+
+	fn is_false_from(&self, ndx: usize) -> bool {
+		BigEndianBitString::is_false_from(&self.0[..], ndx)
+	}
+
+	fn set_true_from(&mut self, ndx: usize) {
+		BigEndianBitString::set_true_from(&mut self.0[..], ndx)
+	}
+
+	fn is_true_from(&self, ndx: usize) -> bool {
+		BigEndianBitString::is_true_from(&self.0[..], ndx)
+	}
+
+	fn bits_prefix_of(&self, prefix_len: usize, value: &Self) -> bool {
+		self.0.bits_prefix_of(prefix_len, &value.0)
+	}
+
+	fn bits_count_ones(&self) -> usize {
+		self.0.bits_count_ones()
+	}
+
+	fn bits_leading_zeros_from(&self, ndx: usize) -> usize {
+		self.0.bits_leading_zeros_from(ndx)
+	}
+
+	fn bits_leading_ones_from(&self, ndx: usize) -> usize {
+		self.0.bits_leading_ones_from(ndx)
+	}
+
+	fn bits_find_next_set(&self, ndx: usize) -> Option<usize> {
+		self.0.bits_find_next_set(ndx)
+	}
+
+	fn bits_find_next_clear(&self, ndx: usize) -> Option<usize> {
+		self.0.bits_find_next_clear(ndx)
+	}
+
+	fn bits_shl_from(&mut self, prefix: usize, count: usize) {
+		self.0.bits_shl_from(prefix, count)
+	}
+
+	fn bits_shr_from(&mut self, prefix: usize, count: usize) {
+		self.0.bits_shr_from(prefix, count)
+	}
+
+	fn bits_rotate_left(&mut self, start: usize, end: usize, count: usize) {
+		self.0.bits_rotate_left(start, end, count)
+	}
+
+	fn bits_rotate_right(&mut self, start: usize, end: usize, count: usize) {
+		self.0.bits_rotate_right(start, end, count)
+	}
+
+	fn bits_extract(&self, offset: usize, width: usize) -> u128 {
+		self.0.bits_extract(offset, width)
+	}
+
+	fn bits_insert(&mut self, offset: usize, width: usize, value: u128) {
+		self.0.bits_insert(offset, width, value)
+	}
+}
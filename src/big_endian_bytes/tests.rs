@@ -0,0 +1,110 @@
+use crate::{
+	BigEndianBytes,
+	BitLengthString,
+	FixedBitString,
+};
+
+#[test]
+fn test_borrowed() {
+	// fake header: 2 bytes, 4 byte address, 2 bytes
+	let mut packet = [0xffu8, 0xff, 10, 0, 0x12, 0x34, 0xee, 0xee];
+
+	let addr = BigEndianBytes::<4>::from_slice(&packet[2..6]).unwrap();
+	assert!(addr.get(4));
+	assert!(!addr.get(0));
+	assert!(BigEndianBytes::new([10, 0, 0, 0]).contains(8, addr));
+	assert!(!BigEndianBytes::new([10, 0, 0, 0]).contains(20, addr));
+	assert_eq!(
+		addr.shared_prefix_len(&BigEndianBytes::new([10, 0, 0x10, 0])),
+		22
+	);
+	assert!(BigEndianBytes::<4>::from_slice(&packet[2..5]).is_none());
+
+	let addr = BigEndianBytes::<4>::from_slice_mut(&mut packet[2..6]).unwrap();
+	addr.set_false_from(16);
+	addr.flip(31);
+	assert!(!addr.inc(24));
+	assert_eq!(packet, [0xff, 0xff, 10, 0, 0, 2, 0xee, 0xee]);
+
+	let bytes = BigEndianBytes::from_mut(&mut packet);
+	bytes.set_true_from(60);
+	assert!(bytes.is_true_from(60));
+	assert_eq!(packet[7], 0xef);
+}
+
+#[test]
+fn test_fixed() {
+	type Bytes = BigEndianBytes<2>;
+	assert_eq!(Bytes::LEN, 16);
+	assert_eq!(Bytes::new_all_true(), Bytes::new([0xff, 0xff]));
+	assert_eq!(Bytes::new_all_false(), Bytes::default());
+
+	let mut b = Bytes::new([0x12, 0xfe]);
+	assert!(!b.inc(8));
+	assert!(b.is_true_from(8));
+	assert!(b.inc(8));
+	assert!(b.is_false_from(8));
+	assert!(!b.inc(7));
+	assert_eq!(b, Bytes::new([0x12, 0x01]));
+
+	let values: [[u8; 2]; 4] = [[0xff, 0xfc], [0xff, 0xfd], [0xff, 0xfe], [0xff, 0xff]];
+	assert!(Bytes::new(values[0])
+		.iter(14)
+		.map(Bytes::into_bytes)
+		.eq(values));
+
+	let prefix = BitLengthString::new(Bytes::new([0xab, 0xcd]), 4);
+	assert_eq!(prefix.bits().as_bytes(), &[0xa0, 0x00]);
+	assert!(Bytes::new([0xa0, 0x00]) < Bytes::new([0xa0, 0x01]));
+}
+
+#[cfg(feature = "net")]
+#[test]
+fn test_net() {
+	use core::net::Ipv6Addr;
+
+	let addr: Ipv6Addr = "2001:db8::1".parse().unwrap();
+	let bytes = BigEndianBytes::from(addr);
+	assert_eq!(bytes.shared_prefix_len(&addr.octets().into()), 128);
+	assert_eq!(Ipv6Addr::from(bytes), addr);
+}
+
+#[cfg(feature = "zerocopy")]
+#[test]
+fn test_zerocopy() {
+	use zerocopy::{
+		FromBytes,
+		Immutable,
+		IntoBytes,
+		KnownLayout,
+		Unaligned,
+	};
+
+	#[derive(FromBytes, IntoBytes, KnownLayout, Immutable, Unaligned)]
+	#[repr(C)]
+	struct Header {
+		kind: u8,
+		src: BigEndianBytes<4>,
+		dst: BigEndianBytes<16>,
+	}
+
+	let mut buf = [0u8; 21];
+	buf[1] = 192;
+	buf[5] = 0x20;
+	let header = Header::mut_from_bytes(&mut buf[..]).unwrap();
+	assert_eq!(header.kind, 0);
+	assert!(header.src.get(0));
+	assert!(header.dst.get(2));
+	header.dst.set_true_from(120);
+	assert_eq!(buf[20], 0xff);
+}
+
+#[cfg(feature = "bytemuck")]
+#[test]
+fn test_bytemuck() {
+	let mut buf = [0u8; 6];
+	let addrs: &mut [BigEndianBytes<2>] = bytemuck::cast_slice_mut(&mut buf);
+	addrs[1].set(0, true);
+	addrs[2].set_true_from(12);
+	assert_eq!(buf, [0, 0, 0x80, 0, 0, 0x0f]);
+}
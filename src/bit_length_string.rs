@@ -56,6 +56,32 @@ impl<W: FixedBitString> BitLengthString<W> {
 	pub fn len(&self) -> usize {
 		self.len
 	}
+
+	/// Bitwise AND of the first `len` bits with `other`
+	pub fn and_with(&mut self, other: &W) {
+		self.bits.and_with(other);
+		self.bits.set_false_from(self.len);
+	}
+
+	/// Bitwise OR of the first `len` bits with `other`; bits of `other`
+	/// after `len` are ignored
+	pub fn or_with(&mut self, other: &W) {
+		self.bits.or_with(other);
+		self.bits.set_false_from(self.len);
+	}
+
+	/// Bitwise XOR of the first `len` bits with `other`; bits of `other`
+	/// after `len` are ignored
+	pub fn xor_with(&mut self, other: &W) {
+		self.bits.xor_with(other);
+		self.bits.set_false_from(self.len);
+	}
+
+	/// Flip the first `len` bits
+	pub fn invert(&mut self) {
+		self.bits.invert();
+		self.bits.set_false_from(self.len);
+	}
 }
 
 impl<W: FixedBitString> BitString for BitLengthString<W> {
@@ -96,10 +122,30 @@ impl<W: FixedBitString> BitString for BitLengthString<W> {
 		}
 	}
 
+	fn max_len(&self) -> Option<usize> {
+		Some(W::LEN)
+	}
+
 	fn shared_prefix_len(&self, other: &Self) -> usize {
 		let max_len = min(self.len, other.len);
 		min(W::shared_prefix_len(&self.bits, &other.bits), max_len)
 	}
+
+	fn count_ones(&self) -> usize {
+		// bits after `len` are always `false`
+		self.bits.count_ones()
+	}
+
+	fn find_next_set(&self, ndx: usize) -> Option<usize> {
+		// bits after `len` are always `false`
+		self.bits.find_next_set(ndx)
+	}
+
+	fn find_next_clear(&self, ndx: usize) -> Option<usize> {
+		self.bits
+			.find_next_clear(ndx)
+			.filter(|&found| found < self.len)
+	}
 }
 
 impl<W: FixedBitString> Default for BitLengthString<W> {
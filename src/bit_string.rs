@@ -76,6 +76,50 @@ pub trait BitString: Eq {
 	/// properly.
 	fn null() -> Self;
 
+	/// Maximum length the bit string can reach through
+	/// [`append`](BitString::append), or `None` if not limited.
+	///
+	/// Allows callers to fail gracefully instead of running into a panic
+	/// in `append`.
+	fn max_len(&self) -> Option<usize> {
+		None
+	}
+
+	/// Number of bits set to `true`.
+	fn count_ones(&self) -> usize {
+		(0..self.len()).filter(|&ndx| self.get(ndx)).count()
+	}
+
+	/// Number of consecutive `false` bits starting at `ndx`.
+	///
+	/// Returns 0 if `ndx >= self.len()`.
+	fn leading_zeros_from(&self, ndx: usize) -> usize {
+		if ndx >= self.len() {
+			return 0;
+		}
+		self.find_next_set(ndx).unwrap_or(self.len()) - ndx
+	}
+
+	/// Number of consecutive `true` bits starting at `ndx`.
+	///
+	/// Returns 0 if `ndx >= self.len()`.
+	fn leading_ones_from(&self, ndx: usize) -> usize {
+		if ndx >= self.len() {
+			return 0;
+		}
+		self.find_next_clear(ndx).unwrap_or(self.len()) - ndx
+	}
+
+	/// Index of the first `true` bit in [ndx..len], or `None`.
+	fn find_next_set(&self, ndx: usize) -> Option<usize> {
+		(ndx..self.len()).find(|&ndx| self.get(ndx))
+	}
+
+	/// Index of the first `false` bit in [ndx..len], or `None`.
+	fn find_next_clear(&self, ndx: usize) -> Option<usize> {
+		(ndx..self.len()).find(|&ndx| !self.get(ndx))
+	}
+
 	/// Length of the longest shared prefix of two bit strings.
 	fn shared_prefix_len(&self, other: &Self) -> usize {
 		let max_len = min(self.len(), other.len());
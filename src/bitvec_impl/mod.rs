@@ -0,0 +1,169 @@
+//! [`BitString`] and [`FixedBitString`] for `bitvec` types.
+//!
+//! - `BitVec<T, O>` is a growable [`BitString`]
+//! - `BitArray<A, O>` is a [`FixedBitString`]
+//!
+//! `BitSlice` doesn't implement [`BitString`] as it can't grow; use the
+//! methods of `bitvec` to modify bits in place.
+//!
+//! Bit indices are the logical bit indices of `bitvec`; with `Msb0` the
+//! memory layout matches the big-endian layout used by this crate.
+//!
+//! `BitLengthString<W>` converts into `BitVec<T, O>`, and a `&BitSlice`
+//! converts back with `TryFrom` if it isn't longer than `W::LEN`.
+
+use bitvec::{
+	array::BitArray,
+	order::BitOrder,
+	slice::BitSlice,
+	store::BitStore,
+	vec::BitVec,
+	view::BitViewSized,
+};
+
+use crate::{
+	convert::ConversionError,
+	BitLengthString,
+	BitString,
+	FixedBitString,
+};
+
+#[cfg(feature = "net")]
+#[cfg(test)]
+mod tests;
+
+impl<T: BitStore, O: BitOrder> BitString for BitVec<T, O> {
+	fn get(&self, ndx: usize) -> bool {
+		self.as_bitslice()[ndx]
+	}
+
+	fn set(&mut self, ndx: usize, bit: bool) {
+		self.as_mut_bitslice().set(ndx, bit);
+	}
+
+	fn flip(&mut self, ndx: usize) {
+		let bits = self.as_mut_bitslice();
+		let bit = bits[ndx];
+		bits.set(ndx, !bit);
+	}
+
+	fn len(&self) -> usize {
+		self.as_bitslice().len()
+	}
+
+	fn clip(&mut self, len: usize) {
+		self.truncate(len);
+	}
+
+	fn append(&mut self, bit: bool) {
+		self.push(bit);
+	}
+
+	fn null() -> Self {
+		BitVec::new()
+	}
+}
+
+impl<A: BitViewSized, O: BitOrder> FixedBitString for BitArray<A, O> {
+	const LEN: usize = 8 * size_of::<A>();
+
+	fn inc(&mut self, prefix: usize) -> bool {
+		assert!(prefix <= Self::LEN);
+		for ndx in (prefix..Self::LEN).rev() {
+			let bit = self[ndx];
+			self.as_mut_bitslice().set(ndx, !bit);
+			if !bit {
+				return false;
+			}
+		}
+		true
+	}
+
+	fn get(&self, ndx: usize) -> bool {
+		self.as_bitslice()[ndx]
+	}
+
+	fn set(&mut self, ndx: usize, bit: bool) {
+		self.as_mut_bitslice().set(ndx, bit);
+	}
+
+	fn shared_prefix_len(&self, other: &Self) -> usize {
+		self.as_bitslice()
+			.iter()
+			.zip(other.as_bitslice())
+			.position(|(a, b)| *a != *b)
+			.unwrap_or(Self::LEN)
+	}
+
+	fn set_false_from(&mut self, ndx: usize) {
+		if ndx < Self::LEN {
+			self[ndx..].fill(false);
+		}
+	}
+
+	fn is_false_from(&self, ndx: usize) -> bool {
+		ndx >= Self::LEN || self[ndx..].not_any()
+	}
+
+	fn set_true_from(&mut self, ndx: usize) {
+		if ndx < Self::LEN {
+			self[ndx..].fill(true);
+		}
+	}
+
+	fn is_true_from(&self, ndx: usize) -> bool {
+		ndx >= Self::LEN || self[ndx..].all()
+	}
+
+	fn new_all_false() -> Self {
+		BitArray::ZERO
+	}
+
+	fn new_all_true() -> Self {
+		!BitArray::ZERO
+	}
+
+	fn contains(&self, prefix: usize, other: &Self) -> bool {
+		assert!(prefix <= Self::LEN);
+		self[..prefix] == other[..prefix]
+	}
+
+	fn count_ones(&self) -> usize {
+		self.as_bitslice().count_ones()
+	}
+
+	fn find_next_set(&self, ndx: usize) -> Option<usize> {
+		self.as_bitslice()
+			.get(ndx..)?
+			.first_one()
+			.map(|found| ndx + found)
+	}
+
+	fn find_next_clear(&self, ndx: usize) -> Option<usize> {
+		self.as_bitslice()
+			.get(ndx..)?
+			.first_zero()
+			.map(|found| ndx + found)
+	}
+}
+
+impl<W: FixedBitString, T: BitStore, O: BitOrder> From<BitLengthString<W>> for BitVec<T, O> {
+	fn from(bits: BitLengthString<W>) -> Self {
+		(0..bits.len()).map(|ndx| bits.bits().get(ndx)).collect()
+	}
+}
+
+impl<W: FixedBitString, T: BitStore, O: BitOrder> TryFrom<&BitSlice<T, O>> for BitLengthString<W> {
+	type Error = ConversionError;
+
+	fn try_from(bits: &BitSlice<T, O>) -> Result<Self, Self::Error> {
+		if bits.len() > W::LEN {
+			return Err(ConversionError::TooLong);
+		}
+		let mut result = W::new_all_false();
+		for ndx in bits.iter_ones() {
+			result.set(ndx, true);
+		}
+		Ok(BitLengthString::new(result, bits.len()))
+	}
+}
@@ -0,0 +1,111 @@
+use alloc::vec::Vec;
+use core::net::Ipv4Addr;
+
+use bitvec::{
+	array::BitArray,
+	bits,
+	bitvec,
+	order::Msb0,
+	vec::BitVec,
+};
+
+use crate::{
+	convert::ConversionError,
+	BitLengthString,
+	BitString,
+	FixedBitString,
+	PrefixMap,
+};
+
+type Prefix = BitLengthString<Ipv4Addr>;
+
+#[test]
+fn test_bit_vec() {
+	let mut v: BitVec<u8, Msb0> = BitString::null();
+	for bit in [true, false, true, true, false] {
+		BitString::append(&mut v, bit);
+	}
+	assert_eq!(v.as_raw_slice(), [0xb0]);
+	BitString::flip(&mut v, 4);
+	BitString::set(&mut v, 0, false);
+	assert_eq!(v, bits![u8, Msb0; 0, 0, 1, 1, 1]);
+	v.clip(3);
+	assert_eq!(BitString::len(&v), 3);
+	assert_eq!(v.shared_prefix_len(&bitvec![u8, Msb0; 0, 0, 0]), 2);
+
+	let mut w: BitVec<u64, Msb0> = bitvec![u64, Msb0; 1; 70];
+	w.clip(65);
+	assert!(BitString::get(&w, 64));
+	assert_eq!(
+		w.subset_cmp(&bitvec![u64, Msb0; 1; 64]),
+		Some(core::cmp::Ordering::Less)
+	);
+}
+
+#[test]
+fn test_prefix_map() {
+	let key = BitVec::<u8, Msb0>::from_slice(&[0x0a, 0x00, 0x00, 0x00]);
+	let prefix = |len: usize| -> BitVec<u8, Msb0> { key[..len].to_bitvec() };
+	let map: PrefixMap<BitVec<u8, Msb0>, u32> = [(prefix(8), 1), (prefix(16), 2), (prefix(4), 3)]
+		.into_iter()
+		.collect();
+	assert_eq!(map.longest_match(&prefix(24)), Some((&prefix(16), &2)));
+	let matches: Vec<u32> = map.matches(&prefix(12)).map(|(_, &v)| v).collect();
+	assert_eq!(matches, [3, 1]);
+}
+
+#[test]
+fn test_bit_array() {
+	type Array = BitArray<[u8; 2], Msb0>;
+	assert_eq!(Array::LEN, 16);
+
+	let mut a = Array::new_all_false();
+	FixedBitString::set(&mut a, 3, true);
+	assert!(a.is_false_from(4));
+	assert!(!a.is_false_from(3));
+	assert!(!a.inc(14));
+	assert!(!a.inc(14));
+	assert!(!a.inc(14));
+	assert_eq!(a.into_inner(), [0x10, 0x03]);
+	assert!(a.inc(14));
+	assert_eq!(a.into_inner(), [0x10, 0x00]);
+	a.set_true_from(12);
+	assert!(a.is_true_from(12));
+	assert_eq!(a.into_inner(), [0x10, 0x0f]);
+	assert!(a.contains(4, &Array::new([0x1f, 0xff])));
+	assert!(!a.contains(5, &Array::new([0x1f, 0xff])));
+	assert_eq!(
+		FixedBitString::shared_prefix_len(&a, &Array::new([0x10, 0x1f])),
+		11
+	);
+	assert_eq!(Array::new_all_true().into_inner(), [0xff, 0xff]);
+
+	let prefix = BitLengthString::new(a, 6);
+	assert_eq!(prefix.bits().into_inner(), [0x10, 0x00]);
+	let values: Vec<[u8; 2]> = Array::new([0xff, 0xfc])
+		.iter(14)
+		.map(BitArray::into_inner)
+		.collect();
+	assert_eq!(
+		values,
+		[[0xff, 0xfc], [0xff, 0xfd], [0xff, 0xfe], [0xff, 0xff]]
+	);
+}
+
+#[test]
+fn test_conversions() {
+	let prefix = Prefix::new(Ipv4Addr::new(10, 128, 0, 0), 9);
+	let v: BitVec<u8, Msb0> = prefix.clone().into();
+	assert_eq!(v.as_raw_slice(), [0x0a, 0x80]);
+	assert_eq!(Prefix::try_from(v.as_bitslice()), Ok(prefix));
+
+	let v: BitVec<u64, Msb0> = bitvec![u64, Msb0; 1; 33];
+	assert_eq!(
+		Prefix::try_from(v.as_bitslice()),
+		Err(ConversionError::TooLong)
+	);
+	assert_eq!(
+		Prefix::try_from(&v[..32]),
+		Ok(Prefix::new(Ipv4Addr::BROADCAST, 32))
+	);
+}
@@ -0,0 +1,126 @@
+use core::{
+	cmp::Ordering,
+	hash::{
+		Hash,
+		Hasher,
+	},
+};
+
+use crate::byte_string;
+
+/// Borrowed byte string viewed as bit string; each byte contributes 8
+/// bits, most significant bit first.
+///
+/// [`Ord`] orders a bit string before all bit strings it is a prefix
+/// of (like [`lexicographic_cmp`]).  For whole bytes this
+/// matches the ordering of `[u8]`, including byte strings with NUL
+/// bytes.  Bits beyond the length are ignored.
+///
+/// The bytes can't be modified, so this type doesn't implement
+/// [`BitString`]; convert to [`ByteString`] (with
+/// [`to_byte_string`](ByteStr::to_byte_string)) for a mutable bit
+/// string, e.g. as key in a [`PrefixMap`].
+///
+/// [`lexicographic_cmp`]: crate::BitString::lexicographic_cmp
+/// [`BitString`]: crate::BitString
+/// [`PrefixMap`]: crate::PrefixMap
+/// [`ByteString`]: crate::ByteString
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ByteStr<'a> {
+	bytes: &'a [u8],
+	/// length in bits; not more than `8 * bytes.len()`
+	len: usize,
+}
+
+impl<'a> ByteStr<'a> {
+	/// Bit string of all bits in `bytes`
+	pub const fn new(bytes: &'a [u8]) -> Self {
+		ByteStr {
+			bytes,
+			len: 8 * bytes.len(),
+		}
+	}
+
+	/// Bit string of the first `len` bits in `bytes`
+	///
+	/// # Panics
+	///
+	/// Panics if `len > 8 * bytes.len()`.
+	pub fn with_len(bytes: &'a [u8], len: usize) -> Self {
+		assert!(len <= 8 * bytes.len());
+		ByteStr {
+			bytes: &bytes[..byte_string::bytes_for(len)],
+			len,
+		}
+	}
+
+	/// Bytes containing the bits
+	///
+	/// If the length isn't a multiple of 8 the last byte also contains
+	/// (unspecified) bits beyond the length.
+	pub fn as_bytes(&self) -> &'a [u8] {
+		self.bytes
+	}
+
+	/// Length of bit string in bits
+	#[allow(clippy::len_without_is_empty)]
+	pub fn len(&self) -> usize {
+		self.len
+	}
+
+	/// Get bit at index `ndx`
+	///
+	/// # Panics
+	///
+	/// Panics if `ndx >= self.len()`.
+	pub fn get(&self, ndx: usize) -> bool {
+		assert!(ndx < self.len);
+		byte_string::get(self.bytes, ndx)
+	}
+
+	/// Length of the longest common prefix of `self` and `other`
+	pub fn shared_prefix_len(&self, other: &Self) -> usize {
+		byte_string::shared_prefix_len(self.bytes, self.len, other.bytes, other.len)
+	}
+
+	/// Copy into a [`ByteString`](crate::ByteString)
+	pub fn to_byte_string(&self) -> crate::ByteString {
+		crate::ByteString::from(*self)
+	}
+}
+
+impl<'a> From<&'a [u8]> for ByteStr<'a> {
+	fn from(bytes: &'a [u8]) -> Self {
+		Self::new(bytes)
+	}
+}
+
+impl<'a> From<&'a str> for ByteStr<'a> {
+	fn from(s: &'a str) -> Self {
+		Self::new(s.as_bytes())
+	}
+}
+
+impl PartialEq for ByteStr<'_> {
+	fn eq(&self, rhs: &Self) -> bool {
+		self.len == rhs.len && self.len == self.shared_prefix_len(rhs)
+	}
+}
+impl Eq for ByteStr<'_> {}
+
+impl Ord for ByteStr<'_> {
+	fn cmp(&self, rhs: &Self) -> Ordering {
+		byte_string::lexicographic_cmp(self.bytes, self.len, rhs.bytes, rhs.len)
+	}
+}
+impl PartialOrd for ByteStr<'_> {
+	fn partial_cmp(&self, rhs: &Self) -> Option<Ordering> {
+		Some(self.cmp(rhs))
+	}
+}
+
+impl Hash for ByteStr<'_> {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		byte_string::hash(self.bytes, self.len, state);
+	}
+}
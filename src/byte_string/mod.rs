@@ -0,0 +1,69 @@
+//! Shared helpers for [`ByteStr`](crate::ByteStr) and
+//! [`ByteString`](crate::ByteString): bits stored in bytes, most
+//! significant bit first.
+
+use core::{
+	cmp::{
+		min,
+		Ordering,
+	},
+	hash::Hasher,
+};
+
+pub(crate) mod borrowed;
+pub(crate) mod owned;
+
+#[cfg(test)]
+mod tests;
+
+/// mask for bit `ndx % 8` within its byte
+fn bit_mask(ndx: usize) -> u8 {
+	0x80 >> (ndx % 8)
+}
+
+/// number of bytes needed to store `len` bits
+fn bytes_for(len: usize) -> usize {
+	len.div_ceil(8)
+}
+
+fn get(bytes: &[u8], ndx: usize) -> bool {
+	0 != bytes[ndx / 8] & bit_mask(ndx)
+}
+
+fn shared_prefix_len(a: &[u8], a_len: usize, b: &[u8], b_len: usize) -> usize {
+	let max_len = min(a_len, b_len);
+	let full = max_len / 8;
+	for (ndx, (x, y)) in a[..full].iter().zip(&b[..full]).enumerate() {
+		if x != y {
+			return ndx * 8 + (x ^ y).leading_zeros() as usize;
+		}
+	}
+	if max_len % 8 != 0 {
+		let diff = a[full] ^ b[full];
+		min(full * 8 + diff.leading_zeros() as usize, max_len)
+	} else {
+		max_len
+	}
+}
+
+fn lexicographic_cmp(a: &[u8], a_len: usize, b: &[u8], b_len: usize) -> Ordering {
+	let spl = shared_prefix_len(a, a_len, b, b_len);
+	if spl == a_len || spl == b_len {
+		a_len.cmp(&b_len)
+	} else if get(a, spl) {
+		Ordering::Greater
+	} else {
+		Ordering::Less
+	}
+}
+
+/// hash bits up to `len`, ignoring unused bits in the last byte
+fn hash<H: Hasher>(bytes: &[u8], len: usize, state: &mut H) {
+	state.write_usize(len);
+	let full = len / 8;
+	state.write(&bytes[..full]);
+	if len % 8 != 0 {
+		let unused = 8 - len % 8;
+		state.write_u8(bytes[full] >> unused << unused);
+	}
+}
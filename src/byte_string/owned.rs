@@ -0,0 +1,228 @@
+use alloc::vec::Vec;
+use core::{
+	cmp::Ordering,
+	hash::{
+		Hash,
+		Hasher,
+	},
+};
+
+use crate::{
+	byte_string,
+	BitString,
+	ByteStr,
+};
+
+/// byte following a NUL byte of the key in [`ByteString::encode_key`]
+const KEY_ESCAPE: u8 = 0xff;
+/// byte following a NUL byte at the end in [`ByteString::encode_key`]
+const KEY_TERMINATOR: u8 = 0x00;
+
+/// Owned byte string as [`BitString`]; each byte contributes 8 bits,
+/// most significant bit first.
+///
+/// Ordering, equality and hashing match [`ByteStr`]; see there for
+/// details.
+///
+/// Keys for crit-bit trees (where no key may be a prefix of another
+/// key) can be created with [`encode_key`](ByteString::encode_key).
+#[derive(Clone, Debug, Default)]
+pub struct ByteString {
+	/// exactly the bytes needed for `len` bits; bits beyond `len` are
+	/// false
+	bytes: Vec<u8>,
+	/// length in bits
+	len: usize,
+}
+
+impl ByteString {
+	/// Create new empty bit string
+	pub const fn new() -> Self {
+		ByteString {
+			bytes: Vec::new(),
+			len: 0,
+		}
+	}
+
+	/// Bytes containing the bits
+	///
+	/// If the length isn't a multiple of 8 the bits in the last byte
+	/// beyond the length are false.
+	pub fn as_bytes(&self) -> &[u8] {
+		&self.bytes
+	}
+
+	/// Length of bit string in bits (same as [`BitString::len()`])
+	#[allow(clippy::len_without_is_empty)]
+	pub fn len(&self) -> usize {
+		self.len
+	}
+
+	/// Borrow as [`ByteStr`]
+	pub fn as_byte_str(&self) -> ByteStr<'_> {
+		ByteStr::with_len(&self.bytes, self.len)
+	}
+
+	/// Encode `key` as bit string that isn't a prefix of the encoding of
+	/// any other key; [`lexicographic_cmp`] of encoded keys matches the
+	/// ordering of `[u8]`.
+	///
+	/// Each byte contributes its 8 bits, apart from NUL bytes which are
+	/// escaped as `00 ff`; the terminator `00 00` marks the end.
+	///
+	/// [`lexicographic_cmp`]: BitString::lexicographic_cmp
+	pub fn encode_key(key: &[u8]) -> Self {
+		let mut bytes = Vec::with_capacity(key.len() + 2);
+		for &byte in key {
+			bytes.push(byte);
+			if 0 == byte {
+				bytes.push(KEY_ESCAPE);
+			}
+		}
+		bytes.extend_from_slice(&[0, KEY_TERMINATOR]);
+		Self::from(bytes)
+	}
+
+	/// Decode a key encoded with [`encode_key`](ByteString::encode_key)
+	///
+	/// Returns `None` if the bit string isn't a valid encoding.
+	pub fn decode_key(&self) -> Option<Vec<u8>> {
+		if self.len % 8 != 0 {
+			return None;
+		}
+		let mut key = Vec::with_capacity(self.bytes.len());
+		let mut bytes = self.bytes.iter();
+		while let Some(&byte) = bytes.next() {
+			if 0 != byte {
+				key.push(byte);
+				continue;
+			}
+			match bytes.next() {
+				Some(&KEY_ESCAPE) => key.push(0),
+				Some(&KEY_TERMINATOR) if bytes.as_slice().is_empty() => return Some(key),
+				_ => return None,
+			}
+		}
+		None
+	}
+
+	/// Return the underlying bytes
+	///
+	/// If the length isn't a multiple of 8 the bits in the last byte
+	/// beyond the length are false.
+	pub fn into_bytes(self) -> Vec<u8> {
+		self.bytes
+	}
+}
+
+impl BitString for ByteString {
+	fn get(&self, ndx: usize) -> bool {
+		assert!(ndx < self.len);
+		byte_string::get(&self.bytes, ndx)
+	}
+
+	fn set(&mut self, ndx: usize, bit: bool) {
+		assert!(ndx < self.len);
+		let mask = byte_string::bit_mask(ndx);
+		if bit {
+			self.bytes[ndx / 8] |= mask;
+		} else {
+			self.bytes[ndx / 8] &= !mask;
+		}
+	}
+
+	fn flip(&mut self, ndx: usize) {
+		assert!(ndx < self.len);
+		self.bytes[ndx / 8] ^= byte_string::bit_mask(ndx);
+	}
+
+	fn len(&self) -> usize {
+		self.len
+	}
+
+	fn clip(&mut self, len: usize) {
+		if len < self.len {
+			self.bytes.truncate(byte_string::bytes_for(len));
+			if len % 8 != 0 {
+				let unused = 8 - len % 8;
+				let last = self.bytes.last_mut().expect("non-empty");
+				*last = *last >> unused << unused;
+			}
+			self.len = len;
+		}
+	}
+
+	fn append(&mut self, bit: bool) {
+		if self.len % 8 == 0 {
+			self.bytes.push(0);
+		}
+		self.len += 1;
+		self.set(self.len - 1, bit);
+	}
+
+	fn null() -> Self {
+		Self::new()
+	}
+
+	fn shared_prefix_len(&self, other: &Self) -> usize {
+		byte_string::shared_prefix_len(&self.bytes, self.len, &other.bytes, other.len)
+	}
+
+	fn lexicographic_cmp(&self, other: &Self) -> Ordering {
+		byte_string::lexicographic_cmp(&self.bytes, self.len, &other.bytes, other.len)
+	}
+}
+
+impl From<Vec<u8>> for ByteString {
+	fn from(bytes: Vec<u8>) -> Self {
+		let len = 8 * bytes.len();
+		ByteString { bytes, len }
+	}
+}
+
+impl From<&[u8]> for ByteString {
+	fn from(bytes: &[u8]) -> Self {
+		Self::from(bytes.to_vec())
+	}
+}
+
+impl From<&str> for ByteString {
+	fn from(s: &str) -> Self {
+		Self::from(s.as_bytes())
+	}
+}
+
+impl From<ByteStr<'_>> for ByteString {
+	fn from(s: ByteStr<'_>) -> Self {
+		let mut result = Self::from(s.as_bytes());
+		// zero bits beyond length
+		result.len = 8 * result.bytes.len();
+		result.clip(s.len());
+		result
+	}
+}
+
+impl PartialEq for ByteString {
+	fn eq(&self, rhs: &Self) -> bool {
+		// unused bits are false
+		self.len == rhs.len && self.bytes == rhs.bytes
+	}
+}
+impl Eq for ByteString {}
+
+impl Ord for ByteString {
+	fn cmp(&self, rhs: &Self) -> Ordering {
+		self.lexicographic_cmp(rhs)
+	}
+}
+impl PartialOrd for ByteString {
+	fn partial_cmp(&self, rhs: &Self) -> Option<Ordering> {
+		Some(self.cmp(rhs))
+	}
+}
+
+impl Hash for ByteString {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		byte_string::hash(&self.bytes, self.len, state);
+	}
+}
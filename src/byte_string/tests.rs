@@ -0,0 +1,128 @@
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+use crate::{
+	BitString,
+	ByteStr,
+	ByteString,
+	PrefixMap,
+};
+
+const WORDS: &[&[u8]] = &[
+	b"", b"\0", b"\0\0", b"\0a", b"a", b"a\0", b"ab", b"abc", b"abd", b"b", b"\x7f", b"\x80",
+	b"\xff", b"\xff\0",
+];
+
+#[test]
+fn test_ordering() {
+	for &a in WORDS {
+		for &b in WORDS {
+			let (sa, sb) = (ByteStr::new(a), ByteStr::new(b));
+			assert_eq!(sa.cmp(&sb), a.cmp(b), "{a:?} <=> {b:?}");
+			assert_eq!(sa == sb, a == b);
+			let (oa, ob) = (ByteString::from(a), ByteString::from(b));
+			assert_eq!(oa.lexicographic_cmp(&ob), a.cmp(b), "{a:?} <=> {b:?}");
+			let prefix = b.starts_with(a);
+			assert_eq!(
+				oa.subset_cmp(&ob),
+				match (prefix, a.starts_with(b)) {
+					(true, true) => Some(Ordering::Equal),
+					(true, false) => Some(Ordering::Greater),
+					(false, true) => Some(Ordering::Less),
+					(false, false) => None,
+				}
+			);
+		}
+	}
+}
+
+#[test]
+fn test_bits() {
+	let s = ByteStr::new(b"\xa5\x0f");
+	let bits: Vec<bool> = (0..s.len()).map(|i| s.get(i)).collect();
+	assert_eq!(
+		bits,
+		[
+			true, false, true, false, false, true, false, true, false, false, false, false, true,
+			true, true, true,
+		]
+	);
+
+	// bits beyond length are ignored
+	let a = ByteStr::with_len(b"\xa5\x3f", 10);
+	assert_eq!(a, ByteStr::with_len(b"\xa5\x00", 10));
+	assert_ne!(a, ByteStr::with_len(b"\xa5\x00", 11));
+	assert_eq!(a.shared_prefix_len(&s), 10);
+	let a = ByteStr::with_len(b"\xa5\x3f", 3);
+	assert_eq!(a.as_bytes(), b"\xa5");
+	assert_eq!(a, ByteStr::with_len(b"\xbf", 3));
+
+	let mut o = ByteString::from(a);
+	assert_eq!(o.as_bytes(), b"\xa0");
+	assert_eq!(o.as_byte_str(), a);
+	for i in 3..16 {
+		o.append(s.get(i));
+	}
+	assert_eq!(o.as_byte_str(), s);
+	o.flip(15);
+	o.set(0, false);
+	assert_eq!(o.as_bytes(), b"\x25\x0e");
+	o.clip(6);
+	assert_eq!(o.into_bytes(), [0x24]);
+}
+
+#[test]
+fn test_prefix_map() {
+	let map: PrefixMap<ByteString, u32> = [
+		(ByteString::from("/usr"), 1),
+		(ByteString::from("/usr/lib"), 2),
+		(ByteString::from("/usr/local"), 3),
+		(ByteString::from("/"), 4),
+		(ByteString::from("/var/lib"), 5),
+	]
+	.into_iter()
+	.collect();
+
+	let keys: Vec<&[u8]> = map.keys().map(|k| k.as_bytes()).collect();
+	assert_eq!(
+		keys,
+		[&b"/"[..], b"/usr", b"/usr/lib", b"/usr/local", b"/var/lib"]
+	);
+
+	let path = ByteString::from("/usr/local/bin");
+	assert_eq!(
+		map.longest_match(&path),
+		Some((&ByteString::from("/usr/local"), &3))
+	);
+	let matches: Vec<u32> = map.matches(&path).map(|(_, &v)| v).collect();
+	assert_eq!(matches, [4, 1, 3]);
+	assert_eq!(map.get(&ByteString::from("/usr/l")), None);
+	assert_eq!(
+		map.longest_match(&ByteString::from("/var/li")),
+		Some((&ByteString::from("/"), &4))
+	);
+}
+
+#[test]
+fn test_encode_key() {
+	for &a in WORDS {
+		let ea = ByteString::encode_key(a);
+		assert_eq!(ea.decode_key().as_deref(), Some(a));
+		for &b in WORDS {
+			let eb = ByteString::encode_key(b);
+			assert_eq!(ea.lexicographic_cmp(&eb), a.cmp(b), "{a:?} <=> {b:?}");
+			// encoded keys are never prefixes of other encoded keys
+			if a != b {
+				assert_eq!(ea.subset_cmp(&eb), None, "{a:?} <=> {b:?}");
+			}
+		}
+	}
+	assert_eq!(ByteString::encode_key(b"a\0").as_bytes(), b"a\0\xff\0\0");
+
+	assert_eq!(ByteString::from(&b"a"[..]).decode_key(), None);
+	assert_eq!(ByteString::from(&b"\0\0a"[..]).decode_key(), None);
+	assert_eq!(ByteString::from(&b"\0\x01\0\0"[..]).decode_key(), None);
+	let mut clipped = ByteString::encode_key(b"a");
+	clipped.clip(20);
+	assert_eq!(clipped.decode_key(), None);
+}
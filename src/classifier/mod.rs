@@ -0,0 +1,125 @@
+//! Multi-field packet classification (5-tuple rules with priorities).
+//!
+//! [`Classifier`] compiles a rule list into a decision tree (HiCuts
+//! style); [`LinearClassifier`] checks all rules one by one and can be
+//! used to validate the results.
+
+use alloc::vec::Vec;
+use core::ops::RangeInclusive;
+
+pub use self::tree::{
+	BuildConfig,
+	Classifier,
+};
+use crate::{
+	BitLengthString,
+	BitString,
+	FixedBitString,
+};
+
+mod tree;
+
+#[cfg(feature = "net")]
+#[cfg(test)]
+mod tests;
+
+/// Fields of a packet header to classify
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Packet<W> {
+	/// source address
+	pub src: W,
+	/// destination address
+	pub dst: W,
+	/// IP protocol number
+	pub protocol: u8,
+	/// source port (use 0 if protocol has no ports)
+	pub src_port: u16,
+	/// destination port (use 0 if protocol has no ports)
+	pub dst_port: u16,
+}
+
+/// Classification rule
+///
+/// A packet matches if all fields match; if multiple rules match a
+/// packet, the rule with the highest `priority` wins; among rules with
+/// the same priority the first rule in the list wins.
+#[derive(Clone, Debug)]
+pub struct Rule<W: FixedBitString, T> {
+	/// source prefix
+	pub src: BitLengthString<W>,
+	/// destination prefix
+	pub dst: BitLengthString<W>,
+	/// IP protocol number; `None` matches all protocols
+	pub protocol: Option<u8>,
+	/// source port range
+	pub src_ports: RangeInclusive<u16>,
+	/// destination port range
+	pub dst_ports: RangeInclusive<u16>,
+	/// priority; higher values win
+	pub priority: u32,
+	/// data to return for matching packets
+	pub action: T,
+}
+
+impl<W: FixedBitString, T> Rule<W, T> {
+	/// Create rule matching all packets
+	pub fn new(priority: u32, action: T) -> Self {
+		Rule {
+			src: BitLengthString::null(),
+			dst: BitLengthString::null(),
+			protocol: None,
+			src_ports: 0..=u16::MAX,
+			dst_ports: 0..=u16::MAX,
+			priority,
+			action,
+		}
+	}
+
+	/// Whether packet matches all fields of the rule
+	pub fn matches(&self, packet: &Packet<W>) -> bool {
+		self.src.contains(&packet.src)
+			&& self.dst.contains(&packet.dst)
+			&& self
+				.protocol
+				.map_or(true, |protocol| protocol == packet.protocol)
+			&& self.src_ports.contains(&packet.src_port)
+			&& self.dst_ports.contains(&packet.dst_port)
+	}
+}
+
+/// Rule indices sorted by precedence (highest priority first, then
+/// list order)
+fn precedence_order<W: FixedBitString, T>(rules: &[Rule<W, T>]) -> Vec<u32> {
+	let mut order: Vec<u32> = (0..rules.len() as u32).collect();
+	// stable sort keeps list order for equal priorities
+	order.sort_by_key(|&ndx| core::cmp::Reverse(rules[ndx as usize].priority));
+	order
+}
+
+/// Reference classifier checking all rules in order of precedence
+#[derive(Clone, Debug)]
+pub struct LinearClassifier<W: FixedBitString, T> {
+	rules: Vec<Rule<W, T>>,
+	order: Vec<u32>,
+}
+
+impl<W: FixedBitString, T> LinearClassifier<W, T> {
+	/// Create classifier for rule list
+	pub fn new(rules: Vec<Rule<W, T>>) -> Self {
+		let order = precedence_order(&rules);
+		LinearClassifier { rules, order }
+	}
+
+	/// All rules (in original order)
+	pub fn rules(&self) -> &[Rule<W, T>] {
+		&self.rules
+	}
+
+	/// Find rule with highest precedence matching the packet
+	pub fn classify(&self, packet: &Packet<W>) -> Option<&Rule<W, T>> {
+		self.order
+			.iter()
+			.map(|&ndx| &self.rules[ndx as usize])
+			.find(|rule| rule.matches(packet))
+	}
+}
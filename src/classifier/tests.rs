@@ -0,0 +1,150 @@
+use alloc::vec::Vec;
+use core::{
+	net::{
+		Ipv4Addr,
+		Ipv6Addr,
+	},
+	ops::RangeInclusive,
+};
+
+use crate::{
+	classifier::{
+		BuildConfig,
+		Classifier,
+		LinearClassifier,
+		Packet,
+		Rule,
+	},
+	test_utils::lcg,
+	BitLengthString,
+	FixedBitString,
+};
+
+#[test]
+fn test_simple() {
+	let p = |addr: [u8; 4], len| BitLengthString::new(Ipv4Addr::from(addr), len);
+	let rules = alloc::vec![
+		Rule {
+			dst: p([10, 0, 0, 0], 8),
+			protocol: Some(6),
+			dst_ports: 22..=22,
+			..Rule::new(10, "ssh")
+		},
+		Rule {
+			src: p([192, 168, 0, 0], 16),
+			..Rule::new(5, "lan")
+		},
+		Rule {
+			dst: p([10, 0, 0, 0], 8),
+			..Rule::new(10, "internal")
+		},
+		Rule {
+			dst_ports: RangeInclusive::new(1, 0),
+			..Rule::new(100, "never")
+		},
+		Rule::new(0, "default"),
+	];
+	let classifier = Classifier::with_config(
+		rules,
+		BuildConfig {
+			leaf_size: 1,
+			..BuildConfig::default()
+		},
+	);
+	let packet = |src: [u8; 4], dst: [u8; 4], protocol, dst_port| Packet {
+		src: Ipv4Addr::from(src),
+		dst: Ipv4Addr::from(dst),
+		protocol,
+		src_port: 1024,
+		dst_port,
+	};
+	let classify = |packet| classifier.classify(&packet).map(|rule| rule.action);
+	assert_eq!(
+		classify(packet([192, 168, 1, 1], [10, 1, 1, 1], 6, 22)),
+		Some("ssh")
+	);
+	assert_eq!(
+		classify(packet([192, 168, 1, 1], [10, 1, 1, 1], 6, 80)),
+		Some("internal")
+	);
+	assert_eq!(
+		classify(packet([192, 168, 1, 1], [11, 1, 1, 1], 6, 80)),
+		Some("lan")
+	);
+	assert_eq!(
+		classify(packet([192, 169, 1, 1], [11, 1, 1, 1], 6, 80)),
+		Some("default")
+	);
+}
+
+fn random_rules<W: FixedBitString + Clone>(
+	next: &mut impl FnMut() -> u32,
+	random_addr: &mut impl FnMut(&mut dyn FnMut() -> u32) -> W,
+	count: usize,
+) -> Vec<Rule<W, usize>> {
+	(0..count)
+		.map(|i| {
+			let src_len = [0, 4, 8, 12, 16, W::LEN][next() as usize % 6];
+			let dst_len = [0, 4, 8, 12, 16, W::LEN][next() as usize % 6];
+			let port = |next: &mut dyn FnMut() -> u32| match next() % 3 {
+				0 => 0..=u16::MAX,
+				1 => {
+					let port = (next() % 8) as u16 * 1000;
+					port..=port
+				},
+				_ => {
+					let start = (next() % 8) as u16 * 1000;
+					start..=start + (next() % 3000) as u16
+				},
+			};
+			Rule {
+				src: BitLengthString::new(random_addr(next), src_len),
+				dst: BitLengthString::new(random_addr(next), dst_len),
+				protocol: [None, Some(6), Some(17)][next() as usize % 3],
+				src_ports: port(next),
+				dst_ports: port(next),
+				..Rule::new(next() % 4, i)
+			}
+		})
+		.collect()
+}
+
+fn check_random<W: FixedBitString + Clone + core::fmt::Debug>(
+	seed: u32,
+	mut random_addr: impl FnMut(&mut dyn FnMut() -> u32) -> W,
+) {
+	// the low bits of the LCG aren't very random
+	let mut lcg = lcg(seed);
+	let mut next = move || lcg() >> 4;
+	let rules = random_rules(&mut next, &mut random_addr, 300);
+	let linear = LinearClassifier::new(rules.clone());
+	let classifier = Classifier::new(rules);
+	for _ in 0..5000 {
+		let packet = Packet {
+			src: random_addr(&mut next),
+			dst: random_addr(&mut next),
+			protocol: [1, 6, 17][next() as usize % 3],
+			src_port: (next() % 8000) as u16,
+			dst_port: (next() % 8000) as u16,
+		};
+		let expected = linear.classify(&packet).map(|rule| rule.action);
+		assert_eq!(
+			classifier.classify(&packet).map(|rule| rule.action),
+			expected
+		);
+	}
+}
+
+#[test]
+fn test_random_ipv4() {
+	check_random(0x2468_ace0, |next| {
+		Ipv4Addr::from_bits((next() << 4) & 0xf0f0_0001)
+	});
+}
+
+#[test]
+fn test_random_ipv6() {
+	check_random(0x1357_9bdf, |next| {
+		Ipv6Addr::from_bits((((next() << 4) & 0xf0f0_0000) as u128) << 96 | (next() & 1) as u128)
+	});
+}
@@ -0,0 +1,408 @@
+use alloc::vec::Vec;
+
+use crate::{
+	classifier::{
+		precedence_order,
+		Packet,
+		Rule,
+	},
+	BitLengthString,
+	BitString,
+	FixedBitString,
+};
+
+/// Dimensions: source and destination address
+const ADDR_DIMS: usize = 2;
+/// Dimensions: protocol, source and destination port
+const INT_DIMS: usize = 3;
+const DIMS: usize = ADDR_DIMS + INT_DIMS;
+/// Bit widths of integer dimensions
+const INT_WIDTHS: [u8; INT_DIMS] = [8, 16, 16];
+
+/// Parameters for building the decision tree of a [`Classifier`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BuildConfig {
+	/// Don't cut nodes with at most this many rules
+	pub leaf_size: usize,
+	/// Limit for the sum of rules in all children plus the number of
+	/// children, relative to the number of rules in a node being cut
+	pub space_factor: usize,
+	/// Maximum number of bits cut at once (i.e. log2 of maximum number
+	/// of children)
+	pub max_cut_bits: u8,
+}
+
+impl Default for BuildConfig {
+	fn default() -> Self {
+		BuildConfig {
+			leaf_size: 8,
+			space_factor: 2,
+			max_cut_bits: 8,
+		}
+	}
+}
+
+/// Aligned range of integers (i.e. an integer prefix)
+#[derive(Clone, Copy, Debug)]
+struct IntPrefix {
+	value: u16,
+	len: u8,
+	width: u8,
+}
+
+impl IntPrefix {
+	fn first(&self) -> u16 {
+		self.value
+	}
+
+	fn last(&self) -> u16 {
+		self.value | ((1u32 << (self.width - self.len)) - 1) as u16
+	}
+
+	fn child(&self, bits: u8, ndx: usize) -> Self {
+		IntPrefix {
+			value: self.value | (ndx << (self.width - self.len - bits)) as u16,
+			len: self.len + bits,
+			width: self.width,
+		}
+	}
+}
+
+/// Part of the search space a tree node is responsible for
+#[derive(Clone)]
+struct Region<W: FixedBitString> {
+	addrs: [BitLengthString<W>; ADDR_DIMS],
+	ints: [IntPrefix; INT_DIMS],
+}
+
+impl<W: FixedBitString + Clone> Region<W> {
+	fn full() -> Self {
+		Region {
+			addrs: [BitLengthString::null(), BitLengthString::null()],
+			ints: INT_WIDTHS.map(|width| IntPrefix {
+				value: 0,
+				len: 0,
+				width,
+			}),
+		}
+	}
+
+	/// (current prefix length, remaining bits) in dimension
+	fn offset_remaining(&self, dim: usize) -> (usize, usize) {
+		if dim < ADDR_DIMS {
+			let len = self.addrs[dim].len();
+			(len, W::LEN - len)
+		} else {
+			let int = &self.ints[dim - ADDR_DIMS];
+			(int.len as usize, (int.width - int.len) as usize)
+		}
+	}
+
+	fn child(&self, dim: usize, bits: u8, ndx: usize) -> Self {
+		let mut child = self.clone();
+		if dim < ADDR_DIMS {
+			for i in (0..bits).rev() {
+				child.addrs[dim].append(0 != ndx & (1 << i));
+			}
+		} else {
+			child.ints[dim - ADDR_DIMS] = self.ints[dim - ADDR_DIMS].child(bits, ndx);
+		}
+		child
+	}
+}
+
+fn int_ranges<W: FixedBitString, T>(rule: &Rule<W, T>) -> [(u16, u16); INT_DIMS] {
+	let protocol = match rule.protocol {
+		Some(protocol) => (protocol as u16, protocol as u16),
+		None => (0, u8::MAX as u16),
+	};
+	[
+		protocol,
+		(*rule.src_ports.start(), *rule.src_ports.end()),
+		(*rule.dst_ports.start(), *rule.dst_ports.end()),
+	]
+}
+
+fn rule_addrs<W: FixedBitString, T>(rule: &Rule<W, T>) -> [&BitLengthString<W>; ADDR_DIMS] {
+	[&rule.src, &rule.dst]
+}
+
+/// Range of children (cutting `bits` bits in dimension `dim`) of
+/// `region` a rule overlapping `region` overlaps
+fn child_range<W: FixedBitString, T>(
+	rule: &Rule<W, T>,
+	region: &Region<W>,
+	dim: usize,
+	bits: u8,
+) -> (usize, usize) {
+	let bits = bits as usize;
+	if dim < ADDR_DIMS {
+		let (rule, region) = (rule_addrs(rule)[dim], &region.addrs[dim]);
+		let offset = region.len();
+		// rule bits fixing the child index
+		let fixed = rule.len().saturating_sub(offset).min(bits);
+		let ndx = (offset..offset + fixed).fold(0, |ndx, i| ndx << 1 | rule.bits().get(i) as usize);
+		let free = bits - fixed;
+		(ndx << free, ((ndx + 1) << free) - 1)
+	} else {
+		let (first, last) = int_ranges(rule)[dim - ADDR_DIMS];
+		let region = &region.ints[dim - ADDR_DIMS];
+		let shift = (region.width - region.len) as usize - bits;
+		let start = (first.max(region.first()) - region.first()) as usize >> shift;
+		let end = (last.min(region.last()) - region.first()) as usize >> shift;
+		(start, end)
+	}
+}
+
+/// Whether `rule` matches all packets in `region` that `other` matches
+///
+/// Both rules must overlap `region`.
+fn covers<W: FixedBitString, T>(rule: &Rule<W, T>, other: &Rule<W, T>, region: &Region<W>) -> bool {
+	let addrs = rule_addrs(rule)
+		.iter()
+		.zip(rule_addrs(other))
+		.zip(&region.addrs)
+		.all(|((rule, other), region)| {
+			// effective prefix of `other` in region is the longer one
+			let other = if other.len() > region.len() {
+				other
+			} else {
+				region
+			};
+			rule.len() <= other.len() && rule.contains(other.bits())
+		});
+	let ints = int_ranges(rule)
+		.iter()
+		.zip(int_ranges(other))
+		.zip(&region.ints)
+		.all(|((&(first, last), (other_first, other_last)), region)| {
+			first <= other_first.max(region.first()) && other_last.min(region.last()) <= last
+		});
+	addrs && ints
+}
+
+fn packet_bits<W: FixedBitString>(
+	packet: &Packet<W>,
+	dim: usize,
+	offset: usize,
+	bits: u8,
+) -> usize {
+	let bits = bits as usize;
+	match dim {
+		0 | 1 => {
+			let addr = if 0 == dim { &packet.src } else { &packet.dst };
+			(offset..offset + bits).fold(0, |ndx, i| ndx << 1 | addr.get(i) as usize)
+		},
+		_ => {
+			let value = [packet.protocol as u16, packet.src_port, packet.dst_port][dim - ADDR_DIMS];
+			let width = INT_WIDTHS[dim - ADDR_DIMS] as usize;
+			(value as usize >> (width - offset - bits)) & ((1 << bits) - 1)
+		},
+	}
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Node {
+	/// rules to check (in `Classifier::leaf_rules`)
+	Leaf { start: u32, end: u32 },
+	/// cut `bits` bits at `offset` in dimension `dim`; child nodes are
+	/// listed in `Classifier::children` starting at `children`
+	Cut {
+		dim: u8,
+		offset: u16,
+		bits: u8,
+		children: u32,
+	},
+}
+
+/// Candidate cut while building the tree
+struct Cut {
+	dim: usize,
+	bits: u8,
+	max_child: usize,
+	cost: usize,
+	children: Vec<Vec<u32>>,
+}
+
+/// Packet classifier using a decision tree (HiCuts style).
+///
+/// Each inner node of the tree cuts the search space by the next few
+/// bits of one header field; leaves contain short lists of rules to
+/// check.
+#[derive(Clone, Debug)]
+pub struct Classifier<W: FixedBitString, T> {
+	rules: Vec<Rule<W, T>>,
+	nodes: Vec<Node>,
+	children: Vec<u32>,
+	leaf_rules: Vec<u32>,
+}
+
+impl<W: FixedBitString + Clone, T> Classifier<W, T> {
+	/// Build classifier for rule list with default [`BuildConfig`]
+	pub fn new(rules: Vec<Rule<W, T>>) -> Self {
+		Self::with_config(rules, BuildConfig::default())
+	}
+
+	/// Build classifier for rule list
+	///
+	/// # Panics
+	///
+	/// Panics if `config.max_cut_bits` is 0 or larger than 16.
+	pub fn with_config(rules: Vec<Rule<W, T>>, config: BuildConfig) -> Self {
+		assert!((1..=16).contains(&config.max_cut_bits));
+		let mut classifier = Classifier {
+			rules,
+			nodes: Vec::new(),
+			children: Vec::new(),
+			leaf_rules: Vec::new(),
+		};
+		let mut order = precedence_order(&classifier.rules);
+		// rules with empty port ranges never match
+		order.retain(|&ndx| {
+			let rule = &classifier.rules[ndx as usize];
+			!rule.src_ports.is_empty() && !rule.dst_ports.is_empty()
+		});
+		classifier.build(&config, order, Region::full());
+		classifier
+	}
+
+	/// Build (sub)tree for rules (in precedence order) in region;
+	/// returns node index
+	fn build(&mut self, config: &BuildConfig, mut rules: Vec<u32>, region: Region<W>) -> u32 {
+		// drop rules that never win in this region
+		let mut ndx = 0;
+		while ndx < rules.len() {
+			let rule = &self.rules[rules[ndx] as usize];
+			if rules[..ndx]
+				.iter()
+				.any(|&other| covers(&self.rules[other as usize], rule, &region))
+			{
+				rules.remove(ndx);
+			} else {
+				ndx += 1;
+			}
+		}
+
+		let node_ndx = self.nodes.len() as u32;
+		let cut = if rules.len() > config.leaf_size {
+			self.find_cut(config, &rules, &region)
+		} else {
+			None
+		};
+		let Some(cut) = cut else {
+			let start = self.leaf_rules.len() as u32;
+			self.leaf_rules.extend(&rules);
+			let end = self.leaf_rules.len() as u32;
+			self.nodes.push(Node::Leaf { start, end });
+			return node_ndx;
+		};
+
+		let (offset, _) = region.offset_remaining(cut.dim);
+		let children = self.children.len();
+		self.children.resize(children + cut.children.len(), 0);
+		self.nodes.push(Node::Cut {
+			dim: cut.dim as u8,
+			offset: offset as u16,
+			bits: cut.bits,
+			children: children as u32,
+		});
+		for (ndx, child_rules) in cut.children.into_iter().enumerate() {
+			let child_region = region.child(cut.dim, cut.bits, ndx);
+			self.children[children + ndx] = self.build(config, child_rules, child_region);
+		}
+		node_ndx
+	}
+
+	/// Find best cut.
+	///
+	/// For each dimension cut as many bits as the space limit allows;
+	/// then take the dimension with the smallest product of the maximum
+	/// number of rules in a child and the cost.
+	fn find_cut(&self, config: &BuildConfig, rules: &[u32], region: &Region<W>) -> Option<Cut> {
+		let mut best: Option<(Cut, Vec<(usize, usize)>)> = None;
+		for dim in 0..DIMS {
+			let (_, remaining) = region.offset_remaining(dim);
+			let max_bits = remaining.min(config.max_cut_bits as usize) as u8;
+			let mut dim_best = None;
+			for bits in 1..=max_bits {
+				let ranges: Vec<(usize, usize)> = rules
+					.iter()
+					.map(|&rule| child_range(&self.rules[rule as usize], region, dim, bits))
+					.collect();
+				// count rules per child using differences
+				let mut diffs = alloc::vec![0isize; (1 << bits) + 1];
+				for &(start, end) in &ranges {
+					diffs[start] += 1;
+					diffs[end + 1] -= 1;
+				}
+				let (mut current, mut sum, mut max_child) = (0, 0, 0);
+				for diff in &diffs[..1 << bits] {
+					current += diff;
+					sum += current as usize;
+					max_child = max_child.max(current as usize);
+				}
+				let cost = sum + (1 << bits);
+				if bits > 1 && cost > config.space_factor * rules.len() {
+					break;
+				}
+				let cut = Cut {
+					dim,
+					bits,
+					max_child,
+					cost,
+					children: Vec::new(),
+				};
+				// only useful if some rule got separated
+				let separated = sum < rules.len() << bits;
+				dim_best = Some((cut, ranges)).filter(|_| separated);
+			}
+			if let Some((cut, ranges)) = dim_best {
+				let better = match &best {
+					None => true,
+					Some((best, _)) => cut.max_child * cut.cost < best.max_child * best.cost,
+				};
+				if better {
+					best = Some((cut, ranges));
+				}
+			}
+		}
+
+		let (mut cut, ranges) = best?;
+		cut.children = alloc::vec![Vec::new(); 1 << cut.bits];
+		for (&rule, &(start, end)) in rules.iter().zip(&ranges) {
+			for child in &mut cut.children[start..=end] {
+				child.push(rule);
+			}
+		}
+		Some(cut)
+	}
+
+	/// All rules (in original order)
+	pub fn rules(&self) -> &[Rule<W, T>] {
+		&self.rules
+	}
+
+	/// Find rule with highest precedence matching the packet
+	pub fn classify(&self, packet: &Packet<W>) -> Option<&Rule<W, T>> {
+		let mut node = self.nodes[0];
+		loop {
+			match node {
+				Node::Leaf { start, end } => {
+					return self.leaf_rules[start as usize..end as usize]
+						.iter()
+						.map(|&ndx| &self.rules[ndx as usize])
+						.find(|rule| rule.matches(packet));
+				},
+				Node::Cut {
+					dim,
+					offset,
+					bits,
+					children,
+				} => {
+					let ndx = packet_bits(packet, dim as usize, offset as usize, bits);
+					node = self.nodes[self.children[children as usize + ndx] as usize];
+				},
+			}
+		}
+	}
+}
@@ -0,0 +1,50 @@
+use core::net::{
+	Ipv4Addr,
+	Ipv6Addr,
+};
+
+use cidr::{
+	AnyIpCidr,
+	Ipv4Cidr,
+	Ipv6Cidr,
+};
+
+use super::ConversionError;
+use crate::BitLengthString;
+
+macro_rules! impl_cidr {
+	($addr:ty, $cidr:ident, $variant:ident) => {
+		impl From<BitLengthString<$addr>> for $cidr {
+			fn from(prefix: BitLengthString<$addr>) -> Self {
+				$cidr::new(*prefix.bits(), prefix.len() as u8).expect("host bits not set")
+			}
+		}
+
+		impl From<BitLengthString<$addr>> for AnyIpCidr {
+			fn from(prefix: BitLengthString<$addr>) -> Self {
+				AnyIpCidr::$variant(prefix.into())
+			}
+		}
+
+		impl From<$cidr> for BitLengthString<$addr> {
+			fn from(cidr: $cidr) -> Self {
+				BitLengthString::new(cidr.first_address(), cidr.network_length() as usize)
+			}
+		}
+
+		impl TryFrom<AnyIpCidr> for BitLengthString<$addr> {
+			type Error = ConversionError;
+
+			fn try_from(cidr: AnyIpCidr) -> Result<Self, Self::Error> {
+				match cidr {
+					AnyIpCidr::$variant(cidr) => Ok(cidr.into()),
+					AnyIpCidr::Any => Err(ConversionError::AnyAddressFamily),
+					_ => Err(ConversionError::AddressFamilyMismatch),
+				}
+			}
+		}
+	};
+}
+
+impl_cidr!(Ipv4Addr, Ipv4Cidr, V4);
+impl_cidr!(Ipv6Addr, Ipv6Cidr, V6);
@@ -0,0 +1,54 @@
+use core::net::{
+	Ipv4Addr,
+	Ipv6Addr,
+};
+
+use ipnet::{
+	IpNet,
+	Ipv4Net,
+	Ipv6Net,
+};
+
+use super::{
+	checked_prefix,
+	ConversionError,
+};
+use crate::BitLengthString;
+
+macro_rules! impl_ipnet {
+	($addr:ty, $net:ident, $variant:ident) => {
+		impl From<BitLengthString<$addr>> for $net {
+			fn from(prefix: BitLengthString<$addr>) -> Self {
+				$net::new(*prefix.bits(), prefix.len() as u8).expect("valid prefix length")
+			}
+		}
+
+		impl From<BitLengthString<$addr>> for IpNet {
+			fn from(prefix: BitLengthString<$addr>) -> Self {
+				IpNet::$variant(prefix.into())
+			}
+		}
+
+		impl TryFrom<$net> for BitLengthString<$addr> {
+			type Error = ConversionError;
+
+			fn try_from(net: $net) -> Result<Self, Self::Error> {
+				checked_prefix(net.addr(), net.prefix_len())
+			}
+		}
+
+		impl TryFrom<IpNet> for BitLengthString<$addr> {
+			type Error = ConversionError;
+
+			fn try_from(net: IpNet) -> Result<Self, Self::Error> {
+				match net {
+					IpNet::$variant(net) => net.try_into(),
+					_ => Err(ConversionError::AddressFamilyMismatch),
+				}
+			}
+		}
+	};
+}
+
+impl_ipnet!(Ipv4Addr, Ipv4Net, V4);
+impl_ipnet!(Ipv6Addr, Ipv6Net, V6);
@@ -0,0 +1,54 @@
+use core::net::{
+	Ipv4Addr,
+	Ipv6Addr,
+};
+
+use ipnetwork::{
+	IpNetwork,
+	Ipv4Network,
+	Ipv6Network,
+};
+
+use super::{
+	checked_prefix,
+	ConversionError,
+};
+use crate::BitLengthString;
+
+macro_rules! impl_ipnetwork {
+	($addr:ty, $net:ident, $variant:ident) => {
+		impl From<BitLengthString<$addr>> for $net {
+			fn from(prefix: BitLengthString<$addr>) -> Self {
+				$net::new(*prefix.bits(), prefix.len() as u8).expect("valid prefix length")
+			}
+		}
+
+		impl From<BitLengthString<$addr>> for IpNetwork {
+			fn from(prefix: BitLengthString<$addr>) -> Self {
+				IpNetwork::$variant(prefix.into())
+			}
+		}
+
+		impl TryFrom<$net> for BitLengthString<$addr> {
+			type Error = ConversionError;
+
+			fn try_from(net: $net) -> Result<Self, Self::Error> {
+				checked_prefix(net.ip(), net.prefix())
+			}
+		}
+
+		impl TryFrom<IpNetwork> for BitLengthString<$addr> {
+			type Error = ConversionError;
+
+			fn try_from(net: IpNetwork) -> Result<Self, Self::Error> {
+				match net {
+					IpNetwork::$variant(net) => net.try_into(),
+					_ => Err(ConversionError::AddressFamilyMismatch),
+				}
+			}
+		}
+	};
+}
+
+impl_ipnetwork!(Ipv4Addr, Ipv4Network, V4);
+impl_ipnetwork!(Ipv6Addr, Ipv6Network, V6);
@@ -0,0 +1,79 @@
+//! Conversions between [`BitLengthString`] of [`Ipv4Addr`] and
+//! [`Ipv6Addr`] and the network types of other crates.
+//!
+//! - `ipnet`: `Ipv4Net`, `Ipv6Net` and `IpNet`
+//! - `ipnetwork`: `Ipv4Network`, `Ipv6Network` and `IpNetwork`
+//! - `cidr`: `Ipv4Cidr`, `Ipv6Cidr` and `AnyIpCidr`
+//!
+//! The `bitvec` feature also provides conversions between bitvec
+//! containers and [`BitLengthString`]; converting a `BitSlice` fails with
+//! [`ConversionError::TooLong`] if it doesn't fit.
+//!
+//! Converting a [`BitLengthString`] into any of these types always
+//! succeeds (its bits after the length are always `false`).
+//!
+//! `ipnet` and `ipnetwork` allow host bits (bits after the prefix length)
+//! to be set; converting such a network fails with
+//! [`ConversionError::HostBitsSet`] instead of silently dropping the
+//! host bits.  `cidr` types never have host bits set, so `Ipv4Cidr` and
+//! `Ipv6Cidr` convert with `From`.
+//!
+//! [`BitLengthString`]: crate::BitLengthString
+//! [`Ipv4Addr`]: core::net::Ipv4Addr
+//! [`Ipv6Addr`]: core::net::Ipv6Addr
+
+use core::fmt;
+
+#[cfg(feature = "cidr")]
+mod cidr_impl;
+#[cfg(feature = "ipnet")]
+mod ipnet_impl;
+#[cfg(feature = "ipnetwork")]
+mod ipnetwork_impl;
+
+#[cfg(any(feature = "ipnet", feature = "ipnetwork", feature = "cidr"))]
+#[cfg(test)]
+mod tests;
+
+/// Error when converting a network of another crate into a
+/// [`BitLengthString`](crate::BitLengthString)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ConversionError {
+	/// bits after the prefix length are set in the address
+	HostBitsSet,
+	/// network is of the other address family (IPv4 vs IPv6)
+	AddressFamilyMismatch,
+	/// network matching all addresses of any address family (e.g.
+	/// `AnyIpCidr::Any`)
+	AnyAddressFamily,
+	/// bit string is too long for the target type
+	TooLong,
+}
+
+impl fmt::Display for ConversionError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			ConversionError::HostBitsSet => f.write_str("host bits set in network address"),
+			ConversionError::AddressFamilyMismatch => f.write_str("address family mismatch"),
+			ConversionError::AnyAddressFamily => {
+				f.write_str("network not restricted to an address family")
+			},
+			ConversionError::TooLong => f.write_str("bit string too long"),
+		}
+	}
+}
+
+impl core::error::Error for ConversionError {}
+
+/// Build a prefix from an address and length as stored by a network type
+/// of another crate, which might have host bits set
+#[cfg(any(feature = "ipnet", feature = "ipnetwork"))]
+fn checked_prefix<W>(addr: W, len: u8) -> Result<crate::BitLengthString<W>, ConversionError>
+where
+	W: crate::FixedBitString + Clone,
+{
+	if !addr.is_false_from(len as usize) {
+		return Err(ConversionError::HostBitsSet);
+	}
+	Ok(crate::BitLengthString::new(addr, len as usize))
+}
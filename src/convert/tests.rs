@@ -0,0 +1,104 @@
+use core::net::{
+	Ipv4Addr,
+	Ipv6Addr,
+};
+
+use super::ConversionError;
+use crate::BitLengthString;
+
+type Prefix4 = BitLengthString<Ipv4Addr>;
+type Prefix6 = BitLengthString<Ipv6Addr>;
+
+fn p4(s: &str) -> Prefix4 {
+	let (addr, len) = s.split_once('/').unwrap();
+	Prefix4::new(addr.parse().unwrap(), len.parse().unwrap())
+}
+
+fn p6(s: &str) -> Prefix6 {
+	let (addr, len) = s.split_once('/').unwrap();
+	Prefix6::new(addr.parse().unwrap(), len.parse().unwrap())
+}
+
+#[cfg(feature = "ipnet")]
+#[test]
+fn test_ipnet() {
+	use ipnet::{
+		IpNet,
+		Ipv4Net,
+		Ipv6Net,
+	};
+
+	let net: Ipv4Net = p4("10.0.0.0/8").into();
+	assert_eq!(net, "10.0.0.0/8".parse().unwrap());
+	assert_eq!(Prefix4::try_from(net), Ok(p4("10.0.0.0/8")));
+	let net: IpNet = p6("2001:db8::/32").into();
+	assert_eq!(net, "2001:db8::/32".parse().unwrap());
+	assert_eq!(Prefix6::try_from(net), Ok(p6("2001:db8::/32")));
+	assert_eq!(
+		Prefix4::try_from(net),
+		Err(ConversionError::AddressFamilyMismatch)
+	);
+	assert_eq!(
+		Prefix4::try_from("10.0.0.1/8".parse::<Ipv4Net>().unwrap()),
+		Err(ConversionError::HostBitsSet)
+	);
+	assert_eq!(
+		Prefix6::try_from("::1/0".parse::<Ipv6Net>().unwrap()),
+		Err(ConversionError::HostBitsSet)
+	);
+	assert_eq!(
+		Prefix6::try_from("::1/128".parse::<Ipv6Net>().unwrap()),
+		Ok(p6("::1/128"))
+	);
+}
+
+#[cfg(feature = "ipnetwork")]
+#[test]
+fn test_ipnetwork() {
+	use ipnetwork::{
+		IpNetwork,
+		Ipv4Network,
+	};
+
+	let net: IpNetwork = p4("192.168.0.0/16").into();
+	assert_eq!(net, "192.168.0.0/16".parse().unwrap());
+	assert_eq!(Prefix4::try_from(net), Ok(p4("192.168.0.0/16")));
+	assert_eq!(
+		Prefix6::try_from(net),
+		Err(ConversionError::AddressFamilyMismatch)
+	);
+	let net: IpNetwork = p6("fe80::/10").into();
+	assert_eq!(Prefix6::try_from(net), Ok(p6("fe80::/10")));
+	assert_eq!(
+		Prefix4::try_from("192.168.1.1/16".parse::<Ipv4Network>().unwrap()),
+		Err(ConversionError::HostBitsSet)
+	);
+}
+
+#[cfg(feature = "cidr")]
+#[test]
+fn test_cidr() {
+	use cidr::{
+		AnyIpCidr,
+		Ipv4Cidr,
+		Ipv6Cidr,
+	};
+
+	let cidr: Ipv4Cidr = p4("172.16.0.0/12").into();
+	assert_eq!(cidr, "172.16.0.0/12".parse().unwrap());
+	assert_eq!(Prefix4::from(cidr), p4("172.16.0.0/12"));
+	let cidr: Ipv6Cidr = p6("::/0").into();
+	assert_eq!(Prefix6::from(cidr), p6("::/0"));
+
+	let any: AnyIpCidr = p6("2001:db8::/32").into();
+	assert_eq!(any, "2001:db8::/32".parse().unwrap());
+	assert_eq!(Prefix6::try_from(any), Ok(p6("2001:db8::/32")));
+	assert_eq!(
+		Prefix4::try_from(any),
+		Err(ConversionError::AddressFamilyMismatch)
+	);
+	assert_eq!(
+		Prefix4::try_from(AnyIpCidr::Any),
+		Err(ConversionError::AnyAddressFamily)
+	);
+}
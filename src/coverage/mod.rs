@@ -0,0 +1,235 @@
+//! Address space coverage statistics for sets of (possibly overlapping)
+//! prefixes.
+//!
+//! Overlapping prefixes are only counted once: prefixes contained in
+//! another prefix of the set (see [`subset_cmp`]) are dropped first.
+//!
+//! [`subset_cmp`]: crate::BitString::subset_cmp
+
+use alloc::{
+	vec,
+	vec::Vec,
+};
+use core::{
+	cmp::Ordering,
+	fmt,
+};
+
+use crate::{
+	BitLengthString,
+	BitString,
+	FixedBitString,
+};
+
+#[cfg(feature = "net")]
+#[cfg(test)]
+mod tests;
+
+/// Number of addresses
+///
+/// `u128` can't represent the size of the complete IPv6 address space;
+/// that needs the extra [`TwoPow128`](AddressCount::TwoPow128) variant.
+/// Counts for bit strings with more than 128 bits can exceed even that
+/// and saturate to [`MoreThanTwoPow128`](AddressCount::MoreThanTwoPow128).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum AddressCount {
+	/// less than 2^128 addresses
+	Count(u128),
+	/// exactly 2^128 addresses (e.g. the complete IPv6 address space)
+	TwoPow128,
+	/// more than 2^128 addresses (only for bit strings with more than
+	/// 128 bits)
+	MoreThanTwoPow128,
+}
+
+impl AddressCount {
+	/// Number of addresses in a prefix of length `len` with `bits` bits
+	/// in total.
+	///
+	/// # Panics
+	///
+	/// Panics if `len > bits`.
+	pub fn prefix_size(bits: usize, len: usize) -> Self {
+		assert!(len <= bits);
+		let host_bits = bits - len;
+		match host_bits {
+			0..128 => AddressCount::Count(1 << host_bits),
+			128 => AddressCount::TwoPow128,
+			_ => AddressCount::MoreThanTwoPow128,
+		}
+	}
+
+	/// Count as `u128`; `None` for 2^128 or more
+	pub fn checked_u128(self) -> Option<u128> {
+		match self {
+			AddressCount::Count(count) => Some(count),
+			_ => None,
+		}
+	}
+
+	/// Count as `u128`; 2^128 or more saturates to [`u128::MAX`]
+	pub fn saturating_u128(self) -> u128 {
+		self.checked_u128().unwrap_or(u128::MAX)
+	}
+
+	/// Count as (possibly rounded) `f64`; infinite if more than 2^128
+	pub fn to_f64(self) -> f64 {
+		match self {
+			AddressCount::Count(count) => count as f64,
+			AddressCount::TwoPow128 => 2.0 * (1u128 << 127) as f64,
+			AddressCount::MoreThanTwoPow128 => f64::INFINITY,
+		}
+	}
+
+	/// Add two counts, saturating at
+	/// [`MoreThanTwoPow128`](AddressCount::MoreThanTwoPow128)
+	pub fn saturating_add(self, other: Self) -> Self {
+		match (self, other) {
+			(AddressCount::Count(a), AddressCount::Count(b)) => match a.overflowing_add(b) {
+				(sum, false) => AddressCount::Count(sum),
+				(0, true) => AddressCount::TwoPow128,
+				(_, true) => AddressCount::MoreThanTwoPow128,
+			},
+			(AddressCount::Count(0), count) | (count, AddressCount::Count(0)) => count,
+			_ => AddressCount::MoreThanTwoPow128,
+		}
+	}
+}
+
+impl Default for AddressCount {
+	fn default() -> Self {
+		AddressCount::Count(0)
+	}
+}
+
+impl fmt::Display for AddressCount {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			AddressCount::Count(count) => fmt::Display::fmt(count, f),
+			AddressCount::TwoPow128 => f.pad("340282366920938463463374607431768211456"),
+			AddressCount::MoreThanTwoPow128 => f.pad(">340282366920938463463374607431768211456"),
+		}
+	}
+}
+
+/// Number of addresses in `prefix`
+pub fn prefix_size<W: FixedBitString>(prefix: &BitLengthString<W>) -> AddressCount {
+	AddressCount::prefix_size(W::LEN, prefix.len())
+}
+
+/// Sort prefixes and drop all prefixes contained in other prefixes
+///
+/// The remaining prefixes are disjoint.
+pub fn dedup<W, I>(prefixes: I) -> Vec<BitLengthString<W>>
+where
+	W: FixedBitString,
+	I: IntoIterator<Item = BitLengthString<W>>,
+{
+	let mut sorted: Vec<_> = prefixes.into_iter().collect();
+	sorted.sort_unstable();
+	// in lexicographic order all prefixes contained in a prefix directly
+	// follow it
+	let mut result: Vec<BitLengthString<W>> = Vec::with_capacity(sorted.len());
+	for prefix in sorted {
+		match result.last() {
+			Some(last) if prefix.subset_cmp(last).is_some() => (),
+			_ => result.push(prefix),
+		}
+	}
+	result
+}
+
+/// Number of addresses covered by at least one of the prefixes
+pub fn covered<W, I>(prefixes: I) -> AddressCount
+where
+	W: FixedBitString,
+	I: IntoIterator<Item = BitLengthString<W>>,
+{
+	dedup(prefixes)
+		.iter()
+		.map(prefix_size)
+		.fold(AddressCount::default(), AddressCount::saturating_add)
+}
+
+/// Disjoint prefixes covering the addresses in `parent` covered by at
+/// least one of the prefixes; `None` if a prefix contains `parent`
+fn covered_parts<W, I>(parent: &BitLengthString<W>, prefixes: I) -> Option<Vec<BitLengthString<W>>>
+where
+	W: FixedBitString,
+	I: IntoIterator<Item = BitLengthString<W>>,
+{
+	let mut inside = Vec::new();
+	for prefix in prefixes {
+		match prefix.subset_cmp(parent) {
+			// prefix is contained in parent
+			Some(Ordering::Less) => inside.push(prefix),
+			// prefix contains parent
+			Some(_) => return None,
+			None => (),
+		}
+	}
+	Some(dedup(inside))
+}
+
+/// Number of addresses in `parent` covered by at least one of the
+/// prefixes
+pub fn covered_in<W, I>(parent: &BitLengthString<W>, prefixes: I) -> AddressCount
+where
+	W: FixedBitString,
+	I: IntoIterator<Item = BitLengthString<W>>,
+{
+	match covered_parts(parent, prefixes) {
+		Some(parts) => parts
+			.iter()
+			.map(prefix_size)
+			.fold(AddressCount::default(), AddressCount::saturating_add),
+		None => prefix_size(parent),
+	}
+}
+
+/// Fraction of addresses in `parent` covered by at least one of the
+/// prefixes, between `0.0` and `1.0`
+///
+/// Only depends on the prefix lengths relative to `parent`, so it
+/// works for bit strings of any length.
+pub fn covered_fraction<W, I>(parent: &BitLengthString<W>, prefixes: I) -> f64
+where
+	W: FixedBitString,
+	I: IntoIterator<Item = BitLengthString<W>>,
+{
+	match covered_parts(parent, prefixes) {
+		Some(parts) => parts
+			.iter()
+			.map(|prefix| pow2_neg(prefix.len() - parent.len()))
+			.sum(),
+		None => 1.0,
+	}
+}
+
+/// `2^-exp` (rounded to zero if too small)
+fn pow2_neg(exp: usize) -> f64 {
+	// biased exponent of a normal f64 is in `1..=2046`
+	const BIAS: usize = 1023;
+	if exp < BIAS {
+		f64::from_bits(((BIAS - exp) as u64) << 52)
+	} else {
+		// ignore subnormal numbers
+		0.0
+	}
+}
+
+/// Number of prefixes per prefix length
+///
+/// The result has `W::LEN + 1` entries; index `n` holds the number of
+/// prefixes of length `n`.  Doesn't deduplicate prefixes.
+pub fn length_histogram<W, I>(prefixes: I) -> Vec<usize>
+where
+	W: FixedBitString,
+	I: IntoIterator<Item = BitLengthString<W>>,
+{
+	let mut histogram = vec![0; W::LEN + 1];
+	for prefix in prefixes {
+		histogram[prefix.len()] += 1;
+	}
+	histogram
+}
@@ -0,0 +1,133 @@
+use alloc::vec::Vec;
+use core::net::{
+	Ipv4Addr,
+	Ipv6Addr,
+};
+
+use crate::{
+	coverage::{
+		covered,
+		covered_fraction,
+		covered_in,
+		dedup,
+		length_histogram,
+		prefix_size,
+		AddressCount,
+	},
+	BigEndianBytes,
+	BitLengthString,
+};
+
+fn p4(addr: [u8; 4], len: usize) -> BitLengthString<Ipv4Addr> {
+	BitLengthString::new(Ipv4Addr::from(addr), len)
+}
+
+fn p6(addr: &str, len: usize) -> BitLengthString<Ipv6Addr> {
+	BitLengthString::new(addr.parse().unwrap(), len)
+}
+
+#[test]
+fn test_ipv4() {
+	let prefixes = [
+		p4([10, 1, 0, 0], 16),
+		p4([10, 0, 0, 0], 8),
+		p4([10, 1, 2, 0], 24),
+		p4([192, 168, 0, 0], 24),
+		p4([192, 168, 0, 128], 25),
+		p4([192, 168, 1, 0], 24),
+		p4([192, 168, 1, 0], 24),
+	];
+
+	assert_eq!(
+		dedup(prefixes.iter().cloned()),
+		[
+			p4([10, 0, 0, 0], 8),
+			p4([192, 168, 0, 0], 24),
+			p4([192, 168, 1, 0], 24),
+		]
+	);
+	assert_eq!(
+		covered(prefixes.iter().cloned()),
+		AddressCount::Count((1 << 24) + 512)
+	);
+
+	let parent = p4([192, 168, 0, 0], 16);
+	assert_eq!(
+		covered_in(&parent, prefixes.iter().cloned()),
+		AddressCount::Count(512)
+	);
+	assert_eq!(
+		covered_fraction(&parent, prefixes.iter().cloned()),
+		512.0 / 65536.0
+	);
+	assert_eq!(
+		covered_fraction(&p4([10, 1, 0, 0], 16), prefixes.iter().cloned()),
+		1.0
+	);
+	assert_eq!(
+		covered(Vec::<BitLengthString<Ipv4Addr>>::new()),
+		AddressCount::Count(0)
+	);
+
+	let histogram = length_histogram(prefixes.iter().cloned());
+	assert_eq!(histogram.len(), 33);
+	assert_eq!(histogram[8], 1);
+	assert_eq!(histogram[16], 1);
+	assert_eq!(histogram[24], 4);
+	assert_eq!(histogram[25], 1);
+	assert_eq!(histogram.iter().sum::<usize>(), prefixes.len());
+}
+
+#[test]
+fn test_ipv6_full() {
+	let halves = [p6("::", 1), p6("8000::", 1), p6("2001:db8::", 32)];
+	assert_eq!(covered(halves.iter().cloned()), AddressCount::TwoPow128);
+	assert_eq!(covered([p6("::", 0)]), AddressCount::TwoPow128);
+	assert_eq!(covered([p6("::", 1)]), AddressCount::Count(1 << 127));
+	assert_eq!(covered([p6("::1", 128)]), AddressCount::Count(1));
+
+	let all = AddressCount::TwoPow128;
+	assert_eq!(all.checked_u128(), None);
+	assert_eq!(all.saturating_u128(), u128::MAX);
+	assert_eq!(all.to_f64(), 2f64.powi(128));
+	assert_eq!(
+		alloc::format!("{}", all),
+		"340282366920938463463374607431768211456"
+	);
+	assert!(AddressCount::Count(u128::MAX) < all);
+
+	assert_eq!(
+		covered_fraction(&p6("::", 0), [p6("::", 1), p6("c000::", 2)]),
+		0.75
+	);
+}
+
+#[test]
+fn test_long_bit_strings() {
+	type Long = BitLengthString<BigEndianBytes<32>>;
+	let p = |first: u8, len| {
+		let mut bytes = [0; 32];
+		bytes[0] = first;
+		Long::new(BigEndianBytes::new(bytes), len)
+	};
+	assert_eq!(covered_fraction(&p(0, 0), [p(0, 1)]), 0.5);
+	assert_eq!(covered_fraction(&p(0, 0), [p(0, 1), p(0xc0, 2)]), 0.75);
+	assert_eq!(covered_fraction(&p(0x80, 1), [p(0, 0)]), 1.0);
+	assert_eq!(covered_fraction(&p(0, 0), [p(0, 256)]), 0.5f64.powi(256));
+
+	assert_eq!(prefix_size(&p(0, 128)), AddressCount::TwoPow128);
+	assert_eq!(prefix_size(&p(0, 127)), AddressCount::MoreThanTwoPow128);
+	assert_eq!(covered([p(0, 129), p(0x80, 129)]), AddressCount::TwoPow128);
+	assert_eq!(
+		covered([p(0, 128), p(0x80, 128)]),
+		AddressCount::MoreThanTwoPow128
+	);
+	assert_eq!(
+		covered([p(0, 128), p(0x80, 200)]),
+		AddressCount::MoreThanTwoPow128
+	);
+	assert_eq!(covered([p(0, 128)]), AddressCount::TwoPow128);
+	assert_eq!(AddressCount::MoreThanTwoPow128.checked_u128(), None);
+	assert_eq!(AddressCount::MoreThanTwoPow128.to_f64(), f64::INFINITY);
+	assert!(AddressCount::TwoPow128 < AddressCount::MoreThanTwoPow128);
+}
@@ -0,0 +1,278 @@
+//! ASN.1 DER encoding of `BIT STRING` values.
+//!
+//! The contents of a `BIT STRING` consist of an "unused bits" octet
+//! (number of padding bits in the last octet, `0..=7`) followed by the
+//! bits, most significant bit first.  DER requires the padding bits to
+//! be zero; for types defined as named bit lists (e.g. `KeyUsage` in
+//! X.509) DER also requires trailing zero bits to be removed, which the
+//! `*_named_bits` functions handle.
+//!
+//! The functions here don't allocate: encoding writes into a given
+//! buffer, and decoding appends to a [`BitString`] or (with `alloc`)
+//! borrows the input as [`ByteStr`].
+
+use core::fmt;
+
+use crate::BitString;
+#[cfg(feature = "alloc")]
+use crate::ByteStr;
+
+#[cfg(all(feature = "alloc", feature = "net"))]
+pub mod rfc3779;
+
+#[cfg(all(feature = "alloc", feature = "net"))]
+#[cfg(test)]
+mod tests;
+
+/// Universal tag of a `BIT STRING`
+pub const TAG: u8 = 0x03;
+
+/// Error when encoding or decoding DER
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DerError {
+	/// input ended before the end of the value
+	UnexpectedEnd,
+	/// unexpected tag (e.g. not a primitive `BIT STRING`); contains the
+	/// tag found
+	UnexpectedTag(u8),
+	/// indefinite length form, not allowed in DER
+	IndefiniteLength,
+	/// length not encoded in the minimal number of octets
+	NonMinimalLength,
+	/// length doesn't fit into `usize`
+	LengthOverflow,
+	/// contents are empty; there must be at least the "unused bits"
+	/// octet
+	MissingUnusedBits,
+	/// "unused bits" octet larger than 7, or non-zero without any bits
+	InvalidUnusedBits(u8),
+	/// padding bits are not zero
+	NonZeroPadding,
+	/// named bit list has trailing zero bits
+	TrailingZeroBits,
+	/// bit string is too long for the target type
+	TooLong,
+	/// output buffer too small
+	BufferTooSmall,
+}
+
+impl fmt::Display for DerError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			DerError::UnexpectedEnd => f.write_str("unexpected end of DER input"),
+			DerError::UnexpectedTag(tag) => {
+				write!(f, "unexpected tag {tag:#04x}")
+			},
+			DerError::IndefiniteLength => f.write_str("indefinite length not allowed in DER"),
+			DerError::NonMinimalLength => f.write_str("length not minimally encoded"),
+			DerError::LengthOverflow => f.write_str("length too large"),
+			DerError::MissingUnusedBits => f.write_str("BIT STRING without unused bits octet"),
+			DerError::InvalidUnusedBits(unused) => {
+				write!(f, "invalid number of unused bits: {unused}")
+			},
+			DerError::NonZeroPadding => f.write_str("non-zero padding bits in BIT STRING"),
+			DerError::TrailingZeroBits => f.write_str("trailing zero bits in named bit list"),
+			DerError::TooLong => f.write_str("BIT STRING too long"),
+			DerError::BufferTooSmall => f.write_str("output buffer too small"),
+		}
+	}
+}
+
+impl core::error::Error for DerError {}
+
+/// length of the (bit string) contents for `len` bits
+pub(crate) fn contents_len(len: usize) -> usize {
+	1 + len.div_ceil(8)
+}
+
+/// number of octets needed to encode a length `len`
+pub(crate) fn length_len(len: usize) -> usize {
+	if len < 0x80 {
+		1
+	} else {
+		1 + (usize::BITS - len.leading_zeros()).div_ceil(8) as usize
+	}
+}
+
+/// length of bits up to (including) the last `true` bit
+fn named_bits_len<B: BitString>(bits: &B) -> usize {
+	(0..bits.len())
+		.rev()
+		.find(|&ndx| bits.get(ndx))
+		.map_or(0, |ndx| ndx + 1)
+}
+
+/// Length of the complete DER encoding (tag, length and contents) of
+/// `bits`
+pub fn encoded_len<B: BitString>(bits: &B) -> usize {
+	let contents = contents_len(bits.len());
+	1 + length_len(contents) + contents
+}
+
+/// Length of the complete DER encoding of `bits` as named bit list
+pub fn encoded_len_named_bits<B: BitString>(bits: &B) -> usize {
+	let contents = contents_len(named_bits_len(bits));
+	1 + length_len(contents) + contents
+}
+
+fn write_contents<B: BitString>(bits: &B, len: usize, out: &mut [u8]) {
+	out[0] = (len.div_ceil(8) * 8 - len) as u8;
+	let data = &mut out[1..];
+	data.fill(0);
+	for ndx in 0..len {
+		if bits.get(ndx) {
+			data[ndx / 8] |= 0x80 >> (ndx % 8);
+		}
+	}
+}
+
+/// Write tag and length octets of a value with `len` octets into `out`;
+/// returns the number of octets written
+pub(crate) fn write_header(tag: u8, len: usize, out: &mut [u8]) -> Result<usize, DerError> {
+	let header = 1 + length_len(len);
+	let out = out.get_mut(..header).ok_or(DerError::BufferTooSmall)?;
+	out[0] = tag;
+	if len < 0x80 {
+		out[1] = len as u8;
+	} else {
+		let octets = header - 2;
+		out[1] = 0x80 | octets as u8;
+		for (i, o) in out[2..].iter_mut().enumerate() {
+			*o = (len >> (8 * (octets - 1 - i))) as u8;
+		}
+	}
+	Ok(header)
+}
+
+fn write_der<B: BitString>(bits: &B, len: usize, out: &mut [u8]) -> Result<usize, DerError> {
+	let contents = contents_len(len);
+	let total = 1 + length_len(contents) + contents;
+	let out = out.get_mut(..total).ok_or(DerError::BufferTooSmall)?;
+	let header = write_header(TAG, contents, out)?;
+	write_contents(bits, len, &mut out[header..]);
+	Ok(total)
+}
+
+/// Encode `bits` as DER `BIT STRING` (tag, length and contents) into
+/// `out`; returns the number of octets written
+pub fn encode<B: BitString>(bits: &B, out: &mut [u8]) -> Result<usize, DerError> {
+	write_der(bits, bits.len(), out)
+}
+
+/// Encode `bits` as DER `BIT STRING` of a named bit list, i.e. without
+/// trailing zero bits; returns the number of octets written
+pub fn encode_named_bits<B: BitString>(bits: &B, out: &mut [u8]) -> Result<usize, DerError> {
+	write_der(bits, named_bits_len(bits), out)
+}
+
+/// Encode only the contents ("unused bits" octet and bits) of `bits`
+/// into `out`, e.g. for implicitly tagged values; returns the number of
+/// octets written
+pub fn encode_contents<B: BitString>(bits: &B, out: &mut [u8]) -> Result<usize, DerError> {
+	let contents = contents_len(bits.len());
+	let out = out.get_mut(..contents).ok_or(DerError::BufferTooSmall)?;
+	write_contents(bits, bits.len(), out);
+	Ok(contents)
+}
+
+/// Split a DER value with tag `expected` into contents and remaining
+/// input
+pub(crate) fn split_tlv(input: &[u8], expected: u8) -> Result<(&[u8], &[u8]), DerError> {
+	let (&tag, rest) = input.split_first().ok_or(DerError::UnexpectedEnd)?;
+	if tag != expected {
+		return Err(DerError::UnexpectedTag(tag));
+	}
+	let (&first, mut rest) = rest.split_first().ok_or(DerError::UnexpectedEnd)?;
+	let len = if first < 0x80 {
+		first as usize
+	} else if first == 0x80 {
+		return Err(DerError::IndefiniteLength);
+	} else {
+		let octets = (first & 0x7f) as usize;
+		if rest.len() < octets {
+			return Err(DerError::UnexpectedEnd);
+		}
+		let (len_octets, tail) = rest.split_at(octets);
+		rest = tail;
+		if len_octets[0] == 0 {
+			return Err(DerError::NonMinimalLength);
+		}
+		if octets > size_of::<usize>() {
+			return Err(DerError::LengthOverflow);
+		}
+		let len = len_octets.iter().fold(0, |len, &o| (len << 8) | o as usize);
+		if len < 0x80 {
+			return Err(DerError::NonMinimalLength);
+		}
+		len
+	};
+	if rest.len() < len {
+		return Err(DerError::UnexpectedEnd);
+	}
+	Ok(rest.split_at(len))
+}
+
+/// Validate `BIT STRING` contents; returns bytes and number of bits
+pub(crate) fn split_contents(contents: &[u8]) -> Result<(&[u8], usize), DerError> {
+	let (&unused, data) = contents.split_first().ok_or(DerError::MissingUnusedBits)?;
+	if unused > 7 || (data.is_empty() && unused != 0) {
+		return Err(DerError::InvalidUnusedBits(unused));
+	}
+	if let Some(&last) = data.last() {
+		if last & ((1 << unused) - 1) != 0 {
+			return Err(DerError::NonZeroPadding);
+		}
+	}
+	Ok((data, 8 * data.len() - unused as usize))
+}
+
+fn check_named_bits(data: &[u8], len: usize) -> Result<(), DerError> {
+	if len > 0 && 0 == data[(len - 1) / 8] & (0x80 >> ((len - 1) % 8)) {
+		return Err(DerError::TrailingZeroBits);
+	}
+	Ok(())
+}
+
+fn to_bit_string<B: BitString>(data: &[u8], len: usize) -> Result<B, DerError> {
+	let mut bits = B::null();
+	for ndx in 0..len {
+		if bits.max_len().is_some_and(|max_len| bits.len() >= max_len) {
+			return Err(DerError::TooLong);
+		}
+		bits.append(0 != data[ndx / 8] & (0x80 >> (ndx % 8)));
+	}
+	Ok(bits)
+}
+
+/// Decode a DER `BIT STRING` (tag, length and contents); returns the
+/// bit string and the remaining input
+pub fn decode<B: BitString>(input: &[u8]) -> Result<(B, &[u8]), DerError> {
+	let (contents, rest) = split_tlv(input, TAG)?;
+	let (data, len) = split_contents(contents)?;
+	Ok((to_bit_string(data, len)?, rest))
+}
+
+/// Decode a DER `BIT STRING` of a named bit list, which must not have
+/// trailing zero bits; returns the bit string and the remaining input
+pub fn decode_named_bits<B: BitString>(input: &[u8]) -> Result<(B, &[u8]), DerError> {
+	let (contents, rest) = split_tlv(input, TAG)?;
+	let (data, len) = split_contents(contents)?;
+	check_named_bits(data, len)?;
+	Ok((to_bit_string(data, len)?, rest))
+}
+
+/// Decode the contents ("unused bits" octet and bits) of a `BIT STRING`,
+/// e.g. of implicitly tagged values
+pub fn decode_contents<B: BitString>(contents: &[u8]) -> Result<B, DerError> {
+	let (data, len) = split_contents(contents)?;
+	to_bit_string(data, len)
+}
+
+/// Decode a DER `BIT STRING` borrowing the bits from the input; returns
+/// the bit string and the remaining input
+#[cfg(feature = "alloc")]
+pub fn decode_byte_str(input: &[u8]) -> Result<(ByteStr<'_>, &[u8]), DerError> {
+	let (contents, rest) = split_tlv(input, TAG)?;
+	let (data, len) = split_contents(contents)?;
+	Ok((ByteStr::with_len(data, len), rest))
+}
@@ -0,0 +1,367 @@
+//! RFC 3779 IP address blocks (`IPAddrBlocks`) as used in X.509 and
+//! RPKI certificates.
+//!
+//! Prefixes are encoded as `BIT STRING`s of the prefix length; ranges
+//! as pair of `BIT STRING`s with the trailing zero bits of the first
+//! address and the trailing one bits of the last address removed.
+//!
+//! RFC 3779 requires a canonical form: addresses and ranges are sorted,
+//! adjacent or overlapping entries are merged, and ranges that can be
+//! expressed as a single prefix are encoded as prefix.
+//! [`IpAddressChoice::from_ranges`] builds this form, and decoding
+//! rejects everything else.
+//!
+//! Only IPv4 and IPv6 address families without SAFI are supported, as
+//! required for RPKI (RFC 6487).
+
+use alloc::vec::Vec;
+use core::{
+	fmt,
+	net::{
+		Ipv4Addr,
+		Ipv6Addr,
+	},
+	ops::RangeInclusive,
+};
+
+use crate::{
+	der::{
+		self,
+		DerError,
+	},
+	interval_map::{
+		range_prefixes::{
+			cmp_bits,
+			successor,
+		},
+		RangePrefixes,
+	},
+	BitLengthString,
+	BitString,
+	FixedBitString,
+	IntervalMap,
+};
+
+#[cfg(test)]
+mod tests;
+
+const TAG_NULL: u8 = 0x05;
+const TAG_OCTET_STRING: u8 = 0x04;
+const TAG_SEQUENCE: u8 = 0x30;
+
+const AFI_IPV4: [u8; 2] = [0, 1];
+const AFI_IPV6: [u8; 2] = [0, 2];
+
+/// Error when decoding RFC 3779 structures
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Rfc3779Error {
+	/// invalid DER encoding
+	Der(DerError),
+	/// address family is neither IPv4 nor IPv6, or has a SAFI
+	UnsupportedAddressFamily,
+	/// address families not sorted or duplicated
+	UnsortedAddressFamilies,
+	/// addresses or ranges not sorted, overlapping or adjacent
+	NotCanonical,
+	/// range that should have been encoded as prefix
+	RangeIsPrefix,
+	/// first address of range larger than last address
+	InvalidRange,
+	/// range bound with trailing bits that should have been removed
+	UntrimmedRangeBound,
+	/// unexpected data after a value
+	TrailingData,
+}
+
+impl fmt::Display for Rfc3779Error {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Rfc3779Error::Der(err) => fmt::Display::fmt(err, f),
+			Rfc3779Error::UnsupportedAddressFamily => f.write_str("unsupported address family"),
+			Rfc3779Error::UnsortedAddressFamilies => {
+				f.write_str("address families not sorted or duplicated")
+			},
+			Rfc3779Error::NotCanonical => {
+				f.write_str("addresses or ranges not sorted, overlapping or adjacent")
+			},
+			Rfc3779Error::RangeIsPrefix => f.write_str("range should be encoded as prefix"),
+			Rfc3779Error::InvalidRange => f.write_str("range start after range end"),
+			Rfc3779Error::UntrimmedRangeBound => f.write_str("range bound not trimmed"),
+			Rfc3779Error::TrailingData => f.write_str("unexpected trailing data"),
+		}
+	}
+}
+
+impl core::error::Error for Rfc3779Error {}
+
+impl From<DerError> for Rfc3779Error {
+	fn from(err: DerError) -> Self {
+		Rfc3779Error::Der(err)
+	}
+}
+
+/// `IPAddressOrRange`: a prefix or a range of addresses
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum IpAddressOrRange<W: FixedBitString> {
+	/// `addressPrefix`
+	Prefix(BitLengthString<W>),
+	/// `addressRange`: first and last address
+	Range(W, W),
+}
+
+impl<W: FixedBitString + Clone> IpAddressOrRange<W> {
+	/// First address
+	pub fn first(&self) -> W {
+		match self {
+			IpAddressOrRange::Prefix(prefix) => prefix.bits().clone(),
+			IpAddressOrRange::Range(first, _) => first.clone(),
+		}
+	}
+
+	/// Last address
+	pub fn last(&self) -> W {
+		match self {
+			IpAddressOrRange::Prefix(prefix) => {
+				let mut last = prefix.bits().clone();
+				last.set_true_from(prefix.len());
+				last
+			},
+			IpAddressOrRange::Range(_, last) => last.clone(),
+		}
+	}
+}
+
+/// `IPAddressChoice`: resources of an address family
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum IpAddressChoice<W: FixedBitString> {
+	/// `inherit`: resources are inherited from the issuer
+	Inherit,
+	/// `addressesOrRanges`; should be in canonical form (see
+	/// [`from_ranges`](IpAddressChoice::from_ranges))
+	AddressesOrRanges(Vec<IpAddressOrRange<W>>),
+}
+
+impl<W: FixedBitString + Clone> IpAddressChoice<W> {
+	/// Canonical `addressesOrRanges` covering all given ranges
+	///
+	/// Empty ranges (`start > end`) are ignored.
+	pub fn from_ranges<I>(ranges: I) -> Self
+	where
+		I: IntoIterator<Item = RangeInclusive<W>>,
+	{
+		let mut map = IntervalMap::new();
+		for range in ranges {
+			if cmp_bits(range.start(), range.end()).is_le() {
+				map.insert(range, ());
+			}
+		}
+		let items = map
+			.iter()
+			.map(|(first, last, ())| {
+				let mut prefixes = RangePrefixes::new(first.clone(), last.clone());
+				let prefix = prefixes.next().expect("non-empty range");
+				if prefixes.next().is_none() {
+					IpAddressOrRange::Prefix(prefix)
+				} else {
+					IpAddressOrRange::Range(first.clone(), last.clone())
+				}
+			})
+			.collect();
+		IpAddressChoice::AddressesOrRanges(items)
+	}
+
+	/// Canonical `addressesOrRanges` covering all given prefixes
+	pub fn from_prefixes<I>(prefixes: I) -> Self
+	where
+		I: IntoIterator<Item = BitLengthString<W>>,
+	{
+		Self::from_ranges(prefixes.into_iter().map(|prefix| {
+			let prefix = IpAddressOrRange::Prefix(prefix);
+			prefix.first()..=prefix.last()
+		}))
+	}
+}
+
+/// `IPAddrBlocks` with IPv4 and IPv6 resources
+///
+/// Doesn't support SAFIs; families are encoded in canonical order (IPv4
+/// first).
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct IpAddrBlocks {
+	/// IPv4 resources (AFI 1)
+	pub ipv4: Option<IpAddressChoice<Ipv4Addr>>,
+	/// IPv6 resources (AFI 2)
+	pub ipv6: Option<IpAddressChoice<Ipv6Addr>>,
+}
+
+impl IpAddrBlocks {
+	/// DER encoding
+	pub fn to_der(&self) -> Vec<u8> {
+		let mut families = Vec::new();
+		if let Some(choice) = &self.ipv4 {
+			push_family(&mut families, &AFI_IPV4, choice);
+		}
+		if let Some(choice) = &self.ipv6 {
+			push_family(&mut families, &AFI_IPV6, choice);
+		}
+		let mut out = Vec::new();
+		push_tlv(&mut out, TAG_SEQUENCE, &families);
+		out
+	}
+
+	/// Decode DER encoding; fails if there is any data after the value
+	pub fn from_der(input: &[u8]) -> Result<Self, Rfc3779Error> {
+		let (mut families, rest) = der::split_tlv(input, TAG_SEQUENCE)?;
+		if !rest.is_empty() {
+			return Err(Rfc3779Error::TrailingData);
+		}
+		let mut blocks = IpAddrBlocks::default();
+		let mut last_afi: Option<[u8; 2]> = None;
+		while !families.is_empty() {
+			let (family, rest) = der::split_tlv(families, TAG_SEQUENCE)?;
+			families = rest;
+			let (afi, choice) = der::split_tlv(family, TAG_OCTET_STRING)?;
+			let afi: [u8; 2] = afi
+				.try_into()
+				.map_err(|_| Rfc3779Error::UnsupportedAddressFamily)?;
+			if last_afi.is_some_and(|last| last >= afi) {
+				return Err(Rfc3779Error::UnsortedAddressFamilies);
+			}
+			last_afi = Some(afi);
+			match afi {
+				AFI_IPV4 => blocks.ipv4 = Some(decode_choice(choice)?),
+				AFI_IPV6 => blocks.ipv6 = Some(decode_choice(choice)?),
+				_ => return Err(Rfc3779Error::UnsupportedAddressFamily),
+			}
+		}
+		Ok(blocks)
+	}
+}
+
+fn push_tlv(out: &mut Vec<u8>, tag: u8, contents: &[u8]) {
+	let mut header = [0u8; 2 + size_of::<usize>()];
+	let len = der::write_header(tag, contents.len(), &mut header).expect("header fits");
+	out.extend_from_slice(&header[..len]);
+	out.extend_from_slice(contents);
+}
+
+fn push_bit_string<B: BitString>(out: &mut Vec<u8>, bits: &B) {
+	let start = out.len();
+	out.resize(start + der::encoded_len(bits), 0);
+	der::encode(bits, &mut out[start..]).expect("buffer has encoded length");
+}
+
+/// length of `value` without trailing `bit`s
+fn trimmed_len<W: FixedBitString>(value: &W, bit: bool) -> usize {
+	(0..W::LEN)
+		.rev()
+		.find(|&ndx| value.get(ndx) != bit)
+		.map_or(0, |ndx| ndx + 1)
+}
+
+fn push_family<W: FixedBitString + Clone>(
+	out: &mut Vec<u8>,
+	afi: &[u8],
+	choice: &IpAddressChoice<W>,
+) {
+	let mut family = Vec::new();
+	push_tlv(&mut family, TAG_OCTET_STRING, afi);
+	match choice {
+		IpAddressChoice::Inherit => push_tlv(&mut family, TAG_NULL, &[]),
+		IpAddressChoice::AddressesOrRanges(items) => {
+			let mut list = Vec::new();
+			for item in items {
+				match item {
+					IpAddressOrRange::Prefix(prefix) => push_bit_string(&mut list, prefix),
+					IpAddressOrRange::Range(first, last) => {
+						let mut range = Vec::new();
+						let first = BitLengthString::new(first.clone(), trimmed_len(first, false));
+						push_bit_string(&mut range, &first);
+						let last = BitLengthString::new(last.clone(), trimmed_len(last, true));
+						push_bit_string(&mut range, &last);
+						push_tlv(&mut list, TAG_SEQUENCE, &range);
+					},
+				}
+			}
+			push_tlv(&mut family, TAG_SEQUENCE, &list);
+		},
+	}
+	push_tlv(out, TAG_SEQUENCE, &family);
+}
+
+fn decode_choice<W: FixedBitString + Clone>(
+	input: &[u8],
+) -> Result<IpAddressChoice<W>, Rfc3779Error> {
+	let choice = match input.first() {
+		Some(&TAG_NULL) => {
+			let (null, rest) = der::split_tlv(input, TAG_NULL)?;
+			if !null.is_empty() || !rest.is_empty() {
+				return Err(Rfc3779Error::TrailingData);
+			}
+			IpAddressChoice::Inherit
+		},
+		_ => {
+			let (mut list, rest) = der::split_tlv(input, TAG_SEQUENCE)?;
+			if !rest.is_empty() {
+				return Err(Rfc3779Error::TrailingData);
+			}
+			let mut items: Vec<IpAddressOrRange<W>> = Vec::new();
+			while !list.is_empty() {
+				let (item, rest) = decode_item(list)?;
+				list = rest;
+				if let Some(prev) = items.last() {
+					// require a gap between entries
+					match successor(&prev.last()) {
+						Some(next) if cmp_bits(&next, &item.first()).is_lt() => (),
+						_ => return Err(Rfc3779Error::NotCanonical),
+					}
+				}
+				items.push(item);
+			}
+			IpAddressChoice::AddressesOrRanges(items)
+		},
+	};
+	Ok(choice)
+}
+
+fn decode_address<W: FixedBitString>(
+	input: &[u8],
+) -> Result<(BitLengthString<W>, &[u8]), Rfc3779Error> {
+	let (contents, rest) = der::split_tlv(input, der::TAG)?;
+	Ok((der::decode_contents(contents)?, rest))
+}
+
+fn decode_item<W: FixedBitString + Clone>(
+	input: &[u8],
+) -> Result<(IpAddressOrRange<W>, &[u8]), Rfc3779Error> {
+	if input.first() == Some(&der::TAG) {
+		let (prefix, rest) = decode_address(input)?;
+		return Ok((IpAddressOrRange::Prefix(prefix), rest));
+	}
+	let (range, rest) = der::split_tlv(input, TAG_SEQUENCE)?;
+	let (first, range) = decode_address::<W>(range)?;
+	let (last, range) = decode_address::<W>(range)?;
+	if !range.is_empty() {
+		return Err(Rfc3779Error::TrailingData);
+	}
+	if (first.len() > 0 && !first.get(first.len() - 1))
+		|| (last.len() > 0 && last.get(last.len() - 1))
+	{
+		return Err(Rfc3779Error::UntrimmedRangeBound);
+	}
+	let first = first.bits().clone();
+	// removed trailing bits of the last address are one bits
+	let mut last_bits = last.bits().clone();
+	last_bits.set_true_from(last.len());
+	let last = last_bits;
+	if cmp_bits(&first, &last).is_gt() {
+		return Err(Rfc3779Error::InvalidRange);
+	}
+	if RangePrefixes::new(first.clone(), last.clone())
+		.nth(1)
+		.is_none()
+	{
+		return Err(Rfc3779Error::RangeIsPrefix);
+	}
+	Ok((IpAddressOrRange::Range(first, last), rest))
+}
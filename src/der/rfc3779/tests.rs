@@ -0,0 +1,202 @@
+use alloc::vec;
+use core::net::{
+	Ipv4Addr,
+	Ipv6Addr,
+};
+
+use crate::{
+	der::{
+		rfc3779::{
+			IpAddrBlocks,
+			IpAddressChoice,
+			IpAddressOrRange,
+			Rfc3779Error,
+		},
+		DerError,
+	},
+	BitLengthString,
+};
+
+fn ip4(addr: [u8; 4]) -> Ipv4Addr {
+	Ipv4Addr::from(addr)
+}
+
+fn p4(addr: [u8; 4], len: usize) -> BitLengthString<Ipv4Addr> {
+	BitLengthString::new(ip4(addr), len)
+}
+
+fn p6(addr: &str, len: usize) -> BitLengthString<Ipv6Addr> {
+	BitLengthString::new(addr.parse().unwrap(), len)
+}
+
+const SAMPLE: &[u8] = &[
+	0x30, 0x26, // IPAddrBlocks
+	0x30, 0x1c, // IPAddressFamily
+	0x04, 0x02, 0x00, 0x01, // IPv4
+	0x30, 0x16, // addressesOrRanges
+	0x03, 0x02, 0x00, 0x0a, // 10.0.0.0/8
+	0x03, 0x03, 0x04, 0xac, 0x10, // 172.16.0.0/12
+	0x30, 0x0b, // range
+	0x03, 0x03, 0x03, 0xc0, 0xa8, // 192.168.0.0
+	0x03, 0x04, 0x00, 0xc0, 0xa8, 0x02, // 192.168.2.255
+	0x30, 0x06, // IPAddressFamily
+	0x04, 0x02, 0x00, 0x02, // IPv6
+	0x05, 0x00, // inherit
+];
+
+fn sample() -> IpAddrBlocks {
+	IpAddrBlocks {
+		ipv4: Some(IpAddressChoice::from_ranges([
+			ip4([192, 168, 1, 0])..=ip4([192, 168, 2, 255]),
+			ip4([172, 16, 0, 0])..=ip4([172, 31, 255, 255]),
+			ip4([10, 0, 0, 0])..=ip4([10, 127, 255, 255]),
+			ip4([192, 168, 0, 0])..=ip4([192, 168, 0, 255]),
+			ip4([10, 128, 0, 0])..=ip4([10, 255, 255, 255]),
+			ip4([1, 0, 0, 1])..=ip4([1, 0, 0, 0]),
+		])),
+		ipv6: Some(IpAddressChoice::Inherit),
+	}
+}
+
+#[test]
+fn test_encode() {
+	let blocks = sample();
+	assert_eq!(
+		blocks.ipv4,
+		Some(IpAddressChoice::AddressesOrRanges(vec![
+			IpAddressOrRange::Prefix(p4([10, 0, 0, 0], 8)),
+			IpAddressOrRange::Prefix(p4([172, 16, 0, 0], 12)),
+			IpAddressOrRange::Range(ip4([192, 168, 0, 0]), ip4([192, 168, 2, 255])),
+		]))
+	);
+	assert_eq!(blocks.to_der(), SAMPLE);
+	assert_eq!(IpAddrBlocks::from_der(SAMPLE), Ok(blocks));
+
+	let blocks = IpAddrBlocks {
+		ipv4: None,
+		ipv6: Some(IpAddressChoice::from_prefixes([
+			p6("2001:db8::", 32),
+			p6("2001:db8:1::", 48),
+			p6("2001:db9::", 32),
+			p6("::", 0).clone(),
+		])),
+	};
+	assert_eq!(
+		blocks.ipv6,
+		Some(IpAddressChoice::AddressesOrRanges(vec![
+			IpAddressOrRange::Prefix(p6("::", 0))
+		]))
+	);
+	let der = blocks.to_der();
+	assert_eq!(
+		der,
+		[0x30, 0x0b, 0x30, 0x09, 0x04, 0x02, 0x00, 0x02, 0x30, 0x03, 0x03, 0x01, 0x00]
+	);
+	assert_eq!(IpAddrBlocks::from_der(&der), Ok(blocks));
+
+	// range starting at 0 and ending at the last address
+	let blocks = IpAddrBlocks {
+		ipv4: Some(IpAddressChoice::from_ranges([
+			ip4([0, 0, 0, 0])..=ip4([0, 0, 0, 2])
+		])),
+		ipv6: None,
+	};
+	let der = blocks.to_der();
+	assert_eq!(
+		der[8..],
+		[0x30, 0x0c, 0x30, 0x0a, 0x03, 0x01, 0x00, 0x03, 0x05, 0x00, 0x00, 0x00, 0x00, 0x02][..]
+	);
+	assert_eq!(IpAddrBlocks::from_der(&der), Ok(blocks));
+}
+
+#[test]
+fn test_decode_errors() {
+	fn with_ipv4_list(list: &[u8]) -> alloc::vec::Vec<u8> {
+		let mut der = vec![0x30, 8 + list.len() as u8, 0x30, 6 + list.len() as u8];
+		der.extend_from_slice(&[0x04, 0x02, 0x00, 0x01, 0x30, list.len() as u8]);
+		der.extend_from_slice(list);
+		der
+	}
+
+	let cases: &[(&[u8], Rfc3779Error)] = &[
+		// unsorted
+		(
+			&[0x03, 0x02, 0x00, 0x0b, 0x03, 0x02, 0x00, 0x0a],
+			Rfc3779Error::NotCanonical,
+		),
+		// adjacent
+		(
+			&[0x03, 0x02, 0x00, 0x0a, 0x03, 0x02, 0x00, 0x0b],
+			Rfc3779Error::NotCanonical,
+		),
+		// overlapping
+		(
+			&[0x03, 0x02, 0x00, 0x0a, 0x03, 0x03, 0x00, 0x0a, 0x01],
+			Rfc3779Error::NotCanonical,
+		),
+		// range 10.0.0.0 - 10.255.255.255 is a prefix
+		(
+			&[0x30, 0x08, 0x03, 0x02, 0x01, 0x0a, 0x03, 0x02, 0x00, 0x0a],
+			Rfc3779Error::RangeIsPrefix,
+		),
+		// first address with trailing zero bit
+		(
+			&[0x30, 0x08, 0x03, 0x02, 0x00, 0x0a, 0x03, 0x02, 0x00, 0x0a],
+			Rfc3779Error::UntrimmedRangeBound,
+		),
+		// last address with trailing one bit
+		(
+			&[0x30, 0x08, 0x03, 0x02, 0x01, 0x0a, 0x03, 0x02, 0x00, 0x0b],
+			Rfc3779Error::UntrimmedRangeBound,
+		),
+		// 11.0.0.0 - 10.255.255.255
+		(
+			&[0x30, 0x08, 0x03, 0x02, 0x00, 0x0b, 0x03, 0x02, 0x00, 0x0a],
+			Rfc3779Error::InvalidRange,
+		),
+		// prefix longer than 32 bits
+		(
+			&[0x03, 0x06, 0x00, 1, 2, 3, 4, 5],
+			Rfc3779Error::Der(DerError::TooLong),
+		),
+		(
+			&[0x04, 0x00],
+			Rfc3779Error::Der(DerError::UnexpectedTag(0x04)),
+		),
+	];
+	for &(list, err) in cases {
+		assert_eq!(
+			IpAddrBlocks::from_der(&with_ipv4_list(list)),
+			Err(err),
+			"{list:x?}"
+		);
+	}
+
+	let inherit_v4 = [0x30, 0x06, 0x04, 0x02, 0x00, 0x01, 0x05, 0x00];
+	let inherit_v6 = [0x30, 0x06, 0x04, 0x02, 0x00, 0x02, 0x05, 0x00];
+	let mut der = vec![0x30, 0x10];
+	der.extend_from_slice(&inherit_v6);
+	der.extend_from_slice(&inherit_v4);
+	assert_eq!(
+		IpAddrBlocks::from_der(&der),
+		Err(Rfc3779Error::UnsortedAddressFamilies)
+	);
+	der[2..10].copy_from_slice(&inherit_v4);
+	assert_eq!(
+		IpAddrBlocks::from_der(&der),
+		Err(Rfc3779Error::UnsortedAddressFamilies)
+	);
+
+	// with SAFI
+	let safi = [
+		0x30, 0x09, 0x30, 0x07, 0x04, 0x03, 0x00, 0x01, 0x01, 0x05, 0x00,
+	];
+	assert_eq!(
+		IpAddrBlocks::from_der(&safi),
+		Err(Rfc3779Error::UnsupportedAddressFamily)
+	);
+	assert_eq!(
+		IpAddrBlocks::from_der(&[0x30, 0x00, 0x00]),
+		Err(Rfc3779Error::TrailingData)
+	);
+}
@@ -0,0 +1,120 @@
+use core::net::Ipv4Addr;
+
+use crate::{
+	der::{
+		decode,
+		decode_byte_str,
+		decode_contents,
+		decode_named_bits,
+		encode,
+		encode_contents,
+		encode_named_bits,
+		encoded_len,
+		encoded_len_named_bits,
+		DerError,
+	},
+	BigEndianBytes,
+	BitLengthString,
+	BitString,
+	ByteStr,
+};
+
+type Prefix = BitLengthString<Ipv4Addr>;
+
+fn p(addr: [u8; 4], len: usize) -> Prefix {
+	Prefix::new(Ipv4Addr::from(addr), len)
+}
+
+#[test]
+fn test_encode() {
+	let mut buf = [0u8; 256];
+
+	let prefix = p([192, 168, 128, 0], 17);
+	assert_eq!(encoded_len(&prefix), 6);
+	assert_eq!(encode(&prefix, &mut buf), Ok(6));
+	assert_eq!(buf[..6], [0x03, 0x04, 0x07, 192, 168, 128]);
+	assert_eq!(encode_contents(&prefix, &mut buf), Ok(4));
+	assert_eq!(buf[..4], [0x07, 192, 168, 128]);
+
+	assert_eq!(encode(&Prefix::null(), &mut buf), Ok(3));
+	assert_eq!(buf[..3], [0x03, 0x01, 0x00]);
+
+	// keyUsage: digitalSignature (0), keyCertSign (5)
+	let key_usage = p([0x84, 0, 0, 0], 9);
+	assert_eq!(encoded_len_named_bits(&key_usage), 4);
+	assert_eq!(encode_named_bits(&key_usage, &mut buf), Ok(4));
+	assert_eq!(buf[..4], [0x03, 0x02, 0x02, 0x84]);
+	assert_eq!(encode_named_bits(&p([0, 0, 0, 0], 9), &mut buf), Ok(3));
+	assert_eq!(buf[..3], [0x03, 0x01, 0x00]);
+
+	let long = BitLengthString::new(BigEndianBytes::new([0xff; 200]), 1600);
+	assert_eq!(encode(&long, &mut buf), Ok(204));
+	assert_eq!(buf[..5], [0x03, 0x81, 201, 0x00, 0xff]);
+
+	assert_eq!(
+		encode(&prefix, &mut buf[..5]),
+		Err(DerError::BufferTooSmall)
+	);
+}
+
+#[test]
+fn test_decode() {
+	let input = [0x03, 0x04, 0x07, 192, 168, 128, 0x05, 0x00];
+	assert_eq!(
+		decode::<Prefix>(&input),
+		Ok((p([192, 168, 128, 0], 17), &input[6..]))
+	);
+	let (bits, rest) = decode_byte_str(&input).unwrap();
+	assert_eq!(bits, ByteStr::with_len(&[192, 168, 128], 17));
+	assert_eq!(rest, [0x05, 0x00]);
+	assert_eq!(
+		decode_contents::<Prefix>(&input[2..6]),
+		Ok(p([192, 168, 128, 0], 17))
+	);
+
+	let key_usage = [0x03, 0x02, 0x02, 0x84];
+	assert_eq!(
+		decode_named_bits::<Prefix>(&key_usage),
+		Ok((p([0x84, 0, 0, 0], 6), &[][..]))
+	);
+
+	let mut long = [0xffu8; 204];
+	long[..4].copy_from_slice(&[0x03, 0x81, 201, 0x00]);
+	let (bits, _) = decode_byte_str(&long).unwrap();
+	assert_eq!(bits.len(), 1600);
+}
+
+#[test]
+fn test_decode_errors() {
+	let cases: &[(&[u8], DerError)] = &[
+		(&[], DerError::UnexpectedEnd),
+		(&[0x03], DerError::UnexpectedEnd),
+		(&[0x23, 0x01, 0x00], DerError::UnexpectedTag(0x23)),
+		(&[0x03, 0x80, 0x00, 0x00, 0x00], DerError::IndefiniteLength),
+		(&[0x03, 0x81, 0x01, 0x00], DerError::NonMinimalLength),
+		(&[0x03, 0x82, 0x00, 0x81], DerError::NonMinimalLength),
+		(&[0x03, 0x82, 0x01], DerError::UnexpectedEnd),
+		(
+			&[0x03, 0x89, 1, 0, 0, 0, 0, 0, 0, 0, 0],
+			DerError::LengthOverflow,
+		),
+		(&[0x03, 0x03, 0x00, 0x01], DerError::UnexpectedEnd),
+		(&[0x03, 0x00], DerError::MissingUnusedBits),
+		(&[0x03, 0x01, 0x01], DerError::InvalidUnusedBits(1)),
+		(&[0x03, 0x02, 0x08, 0x00], DerError::InvalidUnusedBits(8)),
+		(&[0x03, 0x02, 0x01, 0x01], DerError::NonZeroPadding),
+		(&[0x03, 0x06, 0x00, 1, 2, 3, 4, 5], DerError::TooLong),
+	];
+	for &(input, err) in cases {
+		assert_eq!(decode::<Prefix>(input), Err(err), "{input:x?}");
+	}
+
+	assert_eq!(
+		decode_named_bits::<Prefix>(&[0x03, 0x02, 0x01, 0x84]),
+		Err(DerError::TrailingZeroBits)
+	);
+	assert_eq!(
+		decode_named_bits::<Prefix>(&[0x03, 0x02, 0x00, 0x00]),
+		Err(DerError::TrailingZeroBits)
+	);
+}
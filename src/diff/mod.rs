@@ -0,0 +1,125 @@
+//! Compute differences between two sorted collections.
+//!
+//! Walks both collections once (merge join), so it needs linear time.
+//! Collections keyed by [`BitLengthString`] iterate in
+//! [`lexicographic_cmp`] order, which is also their [`Ord`] order; other
+//! [`BitString`] keys can be compared with [`diff_by`].
+//!
+//! [`BitLengthString`]: crate::BitLengthString
+//! [`BitString`]: crate::BitString
+//! [`lexicographic_cmp`]: crate::BitString::lexicographic_cmp
+
+use core::{
+	cmp::Ordering,
+	iter::Peekable,
+};
+
+#[cfg(all(feature = "net", feature = "alloc"))]
+#[cfg(test)]
+mod tests;
+
+/// Single difference between an old and a new collection
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DiffEvent<K, V> {
+	/// key only present in new collection
+	Added(K, V),
+	/// key only present in old collection
+	Removed(K, V),
+	/// key present in both collections with different values; contains
+	/// key, old value and new value
+	Changed(K, V, V),
+}
+
+impl<K, V> DiffEvent<K, V> {
+	/// Key of the event
+	pub fn key(&self) -> &K {
+		match self {
+			DiffEvent::Added(key, _) => key,
+			DiffEvent::Removed(key, _) => key,
+			DiffEvent::Changed(key, _, _) => key,
+		}
+	}
+}
+
+/// Iterator over [`DiffEvent`]s between two sorted collections.
+///
+/// Created by [`diff`] and [`diff_by`].
+pub struct Diff<A: Iterator, B: Iterator, F> {
+	old: Peekable<A>,
+	new: Peekable<B>,
+	cmp: F,
+}
+
+impl<A, B, F, K, V> Iterator for Diff<A, B, F>
+where
+	A: Iterator<Item = (K, V)>,
+	B: Iterator<Item = (K, V)>,
+	F: FnMut(&K, &K) -> Ordering,
+	V: PartialEq,
+{
+	type Item = DiffEvent<K, V>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		loop {
+			let order = match (self.old.peek(), self.new.peek()) {
+				(None, None) => return None,
+				(Some(_), None) => Ordering::Less,
+				(None, Some(_)) => Ordering::Greater,
+				(Some((old_key, _)), Some((new_key, _))) => (self.cmp)(old_key, new_key),
+			};
+			match order {
+				Ordering::Less => {
+					let (key, value) = self.old.next()?;
+					return Some(DiffEvent::Removed(key, value));
+				},
+				Ordering::Greater => {
+					let (key, value) = self.new.next()?;
+					return Some(DiffEvent::Added(key, value));
+				},
+				Ordering::Equal => {
+					let (key, old_value) = self.old.next()?;
+					let (_, new_value) = self.new.next()?;
+					if old_value != new_value {
+						return Some(DiffEvent::Changed(key, old_value, new_value));
+					}
+				},
+			}
+		}
+	}
+}
+
+/// Compute differences between two collections of `(key, value)` pairs
+/// sorted by the keys using `cmp`.
+///
+/// Keys must be unique in each collection.
+pub fn diff_by<A, B, F, K, V>(old: A, new: B, cmp: F) -> Diff<A::IntoIter, B::IntoIter, F>
+where
+	A: IntoIterator<Item = (K, V)>,
+	B: IntoIterator<Item = (K, V)>,
+	F: FnMut(&K, &K) -> Ordering,
+	V: PartialEq,
+{
+	Diff {
+		old: old.into_iter().peekable(),
+		new: new.into_iter().peekable(),
+		cmp,
+	}
+}
+
+/// Compute differences between two collections of `(key, value)` pairs
+/// sorted by the keys.
+///
+/// Keys must be unique in each collection.
+///
+/// Sorted slices of pairs can be passed as
+/// `slice.iter().map(|(k, v)| (k, v))`.
+#[allow(clippy::type_complexity)]
+pub fn diff<A, B, K, V>(old: A, new: B) -> Diff<A::IntoIter, B::IntoIter, fn(&K, &K) -> Ordering>
+where
+	A: IntoIterator<Item = (K, V)>,
+	B: IntoIterator<Item = (K, V)>,
+	K: Ord,
+	V: PartialEq,
+{
+	diff_by(old, new, K::cmp)
+}
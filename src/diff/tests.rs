@@ -0,0 +1,80 @@
+use alloc::vec::Vec;
+use core::net::Ipv4Addr;
+
+use crate::{
+	diff::{
+		diff,
+		DiffEvent,
+	},
+	test_utils::lcg,
+	BitLengthString,
+	PersistentPrefixMap,
+	PrefixMap,
+};
+
+type Prefix = BitLengthString<Ipv4Addr>;
+
+fn p(addr: [u8; 4], len: usize) -> Prefix {
+	Prefix::new(Ipv4Addr::from(addr), len)
+}
+
+#[test]
+fn test_diff_slices() {
+	let old = [
+		(p([10, 0, 0, 0], 8), 1),
+		(p([10, 0, 0, 0], 16), 2),
+		(p([11, 0, 0, 0], 8), 3),
+	];
+	let new = [
+		(p([0, 0, 0, 0], 0), 0),
+		(p([10, 0, 0, 0], 8), 1),
+		(p([11, 0, 0, 0], 8), 4),
+	];
+	let events: Vec<_> = diff(
+		old.iter().map(|(k, v)| (k, v)),
+		new.iter().map(|(k, v)| (k, v)),
+	)
+	.collect();
+	assert_eq!(
+		events,
+		[
+			DiffEvent::Added(&p([0, 0, 0, 0], 0), &0),
+			DiffEvent::Removed(&p([10, 0, 0, 0], 16), &2),
+			DiffEvent::Changed(&p([11, 0, 0, 0], 8), &3, &4),
+		]
+	);
+}
+
+#[test]
+fn test_diff_maps() {
+	let mut next = lcg(0x0f1e_2d3c);
+	let mut random_prefix = || Prefix::new(Ipv4Addr::from_bits(next() & 0xff00_0000), 8);
+	let old: PrefixMap<_, _> = (0..200).map(|i| (random_prefix(), i % 3)).collect();
+	let mut new = old.clone();
+	for i in 0..50 {
+		new.insert(random_prefix(), i % 3);
+		new.remove(&random_prefix());
+	}
+
+	let mut patched = old.clone();
+	for event in old.diff(&new) {
+		match event {
+			DiffEvent::Added(key, &value) => assert!(patched.insert(key.clone(), value).is_none()),
+			DiffEvent::Removed(key, _) => assert!(patched.remove(key).is_some()),
+			DiffEvent::Changed(key, &old, &new) => {
+				assert_eq!(patched.insert(key.clone(), new), Some(old))
+			},
+		}
+	}
+	assert_eq!(patched, new);
+
+	// persistent maps with the same entries must produce the same events
+	let expected: Vec<_> = old.diff(&new).collect();
+	assert!(!expected.is_empty());
+	let persistent_old: PersistentPrefixMap<_, _> = old.clone().into_iter().collect();
+	let persistent_new: PersistentPrefixMap<_, _> = new.clone().into_iter().collect();
+	let events: Vec<_> = persistent_old.diff(&persistent_new).collect();
+	assert_eq!(events, expected);
+	assert!(events.windows(2).all(|pair| pair[0].key() < pair[1].key()));
+	assert_eq!(persistent_old.diff(&persistent_old).count(), 0);
+}
@@ -1,6 +1,28 @@
 //! contains extra types related to the `FixedBitString` trait.
 
+use core::fmt;
+
 pub use self::iter::Iter;
 
 mod iter;
 pub(crate) mod traits;
+
+#[cfg(feature = "net")]
+#[cfg(test)]
+mod tests;
+
+/// Error returned by
+/// [`FixedBitString::offset_in`](trait.FixedBitString.html#method.offset_in)
+/// and
+/// [`FixedBitString::with_offset`](trait.FixedBitString.html#method.with_offset)
+/// if an offset doesn't fit
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct OffsetOverflow;
+
+impl fmt::Display for OffsetOverflow {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str("offset out of range")
+	}
+}
+
+impl core::error::Error for OffsetOverflow {}
@@ -0,0 +1,333 @@
+use core::net::{
+	Ipv4Addr,
+	Ipv6Addr,
+};
+
+use super::OffsetOverflow;
+use crate::{
+	BigEndianBytes,
+	BitLengthString,
+	FixedBitString,
+};
+
+#[test]
+fn test_offset_ipv4() {
+	let addr = Ipv4Addr::new(192, 168, 1, 17);
+	assert_eq!(addr.offset_in(24), Ok(17));
+	assert_eq!(addr.offset_in(16), Ok(0x111));
+	assert_eq!(addr.offset_in(32), Ok(0));
+	assert_eq!(addr.offset_in(0), Ok(addr.to_bits().into()));
+
+	assert_eq!(
+		addr.with_offset(24, 200),
+		Ok(Ipv4Addr::new(192, 168, 1, 200))
+	);
+	assert_eq!(
+		addr.with_offset(16, 0x1ff),
+		Ok(Ipv4Addr::new(192, 168, 1, 255))
+	);
+	assert_eq!(addr.with_offset(24, 256), Err(OffsetOverflow));
+	assert_eq!(addr.with_offset(32, 0), Ok(addr));
+	assert_eq!(addr.with_offset(32, 1), Err(OffsetOverflow));
+	assert_eq!(addr.with_offset(0, 1 << 32), Err(OffsetOverflow));
+}
+
+#[test]
+fn test_offset_ipv6() {
+	let addr: Ipv6Addr = "2001:db8::1:2".parse().unwrap();
+	assert_eq!(addr.offset_in(64), Ok(0x1_0002));
+	assert_eq!(addr.offset_in(0), Ok(addr.to_bits()));
+	assert_eq!(
+		addr.with_offset(112, 0xffff),
+		Ok("2001:db8::1:ffff".parse().unwrap())
+	);
+	assert_eq!(addr.with_offset(112, 0x1_0000), Err(OffsetOverflow));
+	assert_eq!(
+		addr.with_offset(0, u128::MAX),
+		Ok(Ipv6Addr::from_bits(u128::MAX))
+	);
+}
+
+#[test]
+fn test_offset_generic() {
+	// uses the default implementations
+	let bytes = BigEndianBytes::new([0xc0, 0xa8, 0x01, 0x11]);
+	for prefix in 0..=32 {
+		let addr = Ipv4Addr::from(bytes);
+		assert_eq!(bytes.offset_in(prefix), addr.offset_in(prefix));
+		for offset in [0, 1, 0x5a5a, 1 << 31, 1 << 32] {
+			assert_eq!(
+				bytes.with_offset(prefix, offset).map(Ipv4Addr::from),
+				addr.with_offset(prefix, offset),
+				"{prefix} {offset}"
+			);
+		}
+	}
+
+	let long = BigEndianBytes::<20>::new_all_true();
+	assert_eq!(long.offset_in(32), Ok(u128::MAX));
+	assert_eq!(long.offset_in(31), Err(OffsetOverflow));
+	let zero = BigEndianBytes::<20>::new_all_false();
+	assert_eq!(zero.offset_in(0), Ok(0));
+	let value = zero.with_offset(0, u128::MAX).unwrap();
+	assert!(!value.get(31) && value.is_true_from(32));
+	assert_eq!(value.offset_in(0), Ok(u128::MAX));
+}
+
+/// only implements required methods; uses the default implementations
+/// for everything else
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Plain(Ipv4Addr);
+
+impl FixedBitString for Plain {
+	const LEN: usize = 32;
+
+	fn inc(&mut self, prefix: usize) -> bool {
+		self.0.inc(prefix)
+	}
+
+	fn get(&self, ndx: usize) -> bool {
+		self.0.get(ndx)
+	}
+
+	fn set(&mut self, ndx: usize, bit: bool) {
+		self.0.set(ndx, bit)
+	}
+
+	fn set_false_from(&mut self, ndx: usize) {
+		self.0.set_false_from(ndx)
+	}
+
+	fn is_false_from(&self, ndx: usize) -> bool {
+		self.0.is_false_from(ndx)
+	}
+
+	fn set_true_from(&mut self, ndx: usize) {
+		self.0.set_true_from(ndx)
+	}
+
+	fn is_true_from(&self, ndx: usize) -> bool {
+		self.0.is_true_from(ndx)
+	}
+
+	fn new_all_false() -> Self {
+		Plain(Ipv4Addr::new_all_false())
+	}
+
+	fn new_all_true() -> Self {
+		Plain(Ipv4Addr::new_all_true())
+	}
+
+	fn contains(&self, prefix: usize, other: &Self) -> bool {
+		self.0.contains(prefix, &other.0)
+	}
+}
+
+fn apply<W: FixedBitString>(op: usize, a: &mut W, b: &W) {
+	match op {
+		0 => a.and_with(b),
+		1 => a.or_with(b),
+		2 => a.xor_with(b),
+		_ => a.invert(),
+	}
+}
+
+#[test]
+fn test_bitwise() {
+	let a = 0xf0f0_1234u32;
+	let b = 0xff00_00ffu32;
+	for (op, expected) in [a & b, a | b, a ^ b, !a].into_iter().enumerate() {
+		let expected = Ipv4Addr::from_bits(expected);
+		let mut addr = Ipv4Addr::from_bits(a);
+		apply(op, &mut addr, &Ipv4Addr::from_bits(b));
+		assert_eq!(addr, expected);
+		let mut plain = Plain(Ipv4Addr::from_bits(a));
+		apply(op, &mut plain, &Plain(Ipv4Addr::from_bits(b)));
+		assert_eq!(plain, Plain(expected));
+		let mut bytes = BigEndianBytes::new(a.to_be_bytes());
+		apply(op, &mut bytes, &BigEndianBytes::new(b.to_be_bytes()));
+		assert_eq!(bytes, BigEndianBytes::from(expected));
+	}
+
+	let mut v6 = Ipv6Addr::new_all_false();
+	v6.xor_with(&"ffff::1".parse().unwrap());
+	v6.invert();
+	assert_eq!(
+		v6,
+		"0:ffff:ffff:ffff:ffff:ffff:ffff:fffe"
+			.parse::<Ipv6Addr>()
+			.unwrap()
+	);
+
+	let x = BigEndianBytes::new([0x0f, 0xf0]);
+	let y = BigEndianBytes::new([0x3c, 0x3c]);
+	assert_eq!(x & y, BigEndianBytes::new([0x0c, 0x30]));
+	assert_eq!(x | y, BigEndianBytes::new([0x3f, 0xfc]));
+	assert_eq!(x ^ y, BigEndianBytes::new([0x33, 0xcc]));
+	assert_eq!(!x, BigEndianBytes::new([0xf0, 0x0f]));
+}
+
+#[test]
+fn test_bit_length_string_bitwise() {
+	let mask = Ipv4Addr::new(0xff, 0x0f, 0xff, 0xff);
+	let mut p = BitLengthString::new(Ipv4Addr::new(10, 0xff, 0, 0), 12);
+	p.and_with(&mask);
+	assert_eq!(*p.bits(), Ipv4Addr::new(10, 0, 0, 0));
+	p.or_with(&mask);
+	assert_eq!(*p.bits(), Ipv4Addr::new(0xff, 0x00, 0, 0));
+	assert_eq!(p.len(), 12);
+	p.xor_with(&Ipv4Addr::new(0x0f, 0xff, 0xff, 0xff));
+	assert_eq!(*p.bits(), Ipv4Addr::new(0xf0, 0xf0, 0, 0));
+	p.invert();
+	assert_eq!(*p.bits(), Ipv4Addr::new(0x0f, 0x00, 0, 0));
+	assert_eq!(
+		p,
+		BitLengthString::new(Ipv4Addr::new(0x0f, 0x0f, 0xff, 0xff), 12)
+	);
+}
+
+fn search<W: FixedBitString>(bits: &W, ndx: usize) -> (usize, usize, Option<usize>, Option<usize>) {
+	(
+		bits.leading_zeros_from(ndx),
+		bits.leading_ones_from(ndx),
+		bits.find_next_set(ndx),
+		bits.find_next_clear(ndx),
+	)
+}
+
+#[test]
+fn test_search() {
+	let addrs = [
+		Ipv4Addr::new(0, 0, 0, 0),
+		Ipv4Addr::new(255, 255, 255, 255),
+		Ipv4Addr::new(10, 0, 0, 1),
+		Ipv4Addr::new(255, 255, 0, 255),
+		Ipv4Addr::new(0x80, 0, 0x10, 0),
+	];
+	for addr in addrs {
+		let plain = Plain(addr);
+		let bytes = BigEndianBytes::from(addr);
+		assert_eq!(addr.count_ones(), addr.to_bits().count_ones() as usize);
+		assert_eq!(plain.count_ones(), addr.count_ones());
+		assert_eq!(bytes.count_ones(), addr.count_ones());
+		for ndx in 0..=33 {
+			let expected = search(&plain, ndx);
+			assert_eq!(search(&addr, ndx), expected, "{addr} {ndx}");
+			assert_eq!(search(&bytes, ndx), expected, "{addr} {ndx}");
+		}
+	}
+
+	let addr = Ipv4Addr::new(10, 0, 0, 1);
+	assert_eq!(addr.leading_zeros_from(0), 4);
+	assert_eq!(addr.find_next_set(5), Some(6));
+	assert_eq!(addr.find_next_set(7), Some(31));
+	assert_eq!(addr.find_next_clear(4), Some(5));
+	let v6: Ipv6Addr = "ffff:ffff::".parse().unwrap();
+	assert_eq!(v6.leading_ones_from(0), 32);
+	assert_eq!(v6.find_next_clear(0), Some(32));
+	assert_eq!(v6.find_next_set(32), None);
+	assert_eq!(v6.count_ones(), 32);
+}
+
+fn add<W: FixedBitString>(mut bits: W, prefix: usize, n: u128) -> (W, bool) {
+	let overflow = bits.add(prefix, n);
+	(bits, overflow)
+}
+
+fn sub<W: FixedBitString>(mut bits: W, prefix: usize, n: u128) -> (W, bool) {
+	let overflow = bits.sub(prefix, n);
+	(bits, overflow)
+}
+
+#[test]
+fn test_add_sub_distance() {
+	let addrs = [
+		Ipv4Addr::new(0, 0, 0, 0),
+		Ipv4Addr::new(255, 255, 255, 255),
+		Ipv4Addr::new(10, 0, 0, 1),
+		Ipv4Addr::new(192, 168, 1, 254),
+	];
+	for addr in addrs {
+		for prefix in [0, 1, 8, 24, 30, 31, 32] {
+			for n in [0, 1, 2, 255, 256, 0xffff_ffff, 1 << 32, u128::MAX] {
+				let expected = add(addr, prefix, n);
+				let (plain, overflow) = add(Plain(addr), prefix, n);
+				assert_eq!((plain.0, overflow), expected, "{addr} {prefix} {n}");
+				let (bytes, overflow) = add(BigEndianBytes::from(addr), prefix, n);
+				assert_eq!((Ipv4Addr::from(bytes), overflow), expected);
+
+				let expected = sub(addr, prefix, n);
+				let (plain, overflow) = sub(Plain(addr), prefix, n);
+				assert_eq!((plain.0, overflow), expected, "{addr} {prefix} {n}");
+			}
+			for other in addrs {
+				let expected = addr.distance(prefix, &other);
+				assert_eq!(Plain(addr).distance(prefix, &Plain(other)), expected);
+				assert_eq!(other.distance(prefix, &addr), expected);
+			}
+		}
+	}
+
+	let addr = Ipv4Addr::new(192, 168, 1, 254);
+	assert_eq!(add(addr, 24, 1), (Ipv4Addr::new(192, 168, 1, 255), false));
+	assert_eq!(add(addr, 24, 3), (Ipv4Addr::new(192, 168, 1, 1), true));
+	assert_eq!(add(addr, 16, 3), (Ipv4Addr::new(192, 168, 2, 1), false));
+	assert_eq!(sub(addr, 24, 255), (Ipv4Addr::new(192, 168, 1, 255), true));
+	assert_eq!(add(addr, 24, 256), (addr, true));
+	assert_eq!(addr.distance(16, &Ipv4Addr::new(10, 0, 0, 1)), Ok(0x1fd));
+	assert_eq!(addr.distance(24, &Ipv4Addr::new(10, 0, 0, 1)), Ok(253));
+
+	let v6: Ipv6Addr = "2001:db8::ffff".parse().unwrap();
+	assert_eq!(add(v6, 64, 1), ("2001:db8::1:0".parse().unwrap(), false));
+	assert_eq!(
+		sub(v6, 64, 0x1_0000),
+		("2001:db8:0:0:ffff:ffff:ffff:ffff".parse().unwrap(), true)
+	);
+	assert_eq!(v6.distance(0, &Ipv6Addr::UNSPECIFIED), Ok(v6.to_bits()));
+
+	// more than 128 host bits
+	let zero = BigEndianBytes::<20>::new_all_false();
+	let max = BigEndianBytes::<20>::new_all_true();
+	assert_eq!(add(max, 0, 1), (zero, true));
+	assert_eq!(sub(zero, 0, 1), (max, true));
+	assert_eq!(add(zero, 0, u128::MAX).0.offset_in(0), Ok(u128::MAX));
+	assert_eq!(sub(add(zero, 0, u128::MAX).0, 0, u128::MAX), (zero, false));
+	let (big, overflow) = add(add(zero, 0, u128::MAX).0, 0, 1);
+	assert!(!overflow && big.get(31) && big.is_false_from(32));
+	assert_eq!(big.distance(0, &zero), Err(OffsetOverflow));
+	assert_eq!(big.distance(0, &add(zero, 0, 1).0), Ok(u128::MAX));
+	assert_eq!(max.distance(32, &zero), Ok(u128::MAX));
+}
+
+#[test]
+fn test_bit_string_search() {
+	use crate::BitString;
+
+	// trailing bits of `BitLengthString` are always `false`
+	let p = BitLengthString::new(Ipv4Addr::new(0xff, 0xf0, 0, 0), 14);
+	assert_eq!(p.count_ones(), 12);
+	assert_eq!(p.leading_ones_from(4), 8);
+	assert_eq!(p.leading_zeros_from(12), 2);
+	assert_eq!(p.find_next_clear(12), Some(12));
+	assert_eq!(p.find_next_clear(14), None);
+	assert_eq!(p.find_next_set(12), None);
+	assert_eq!(p.leading_zeros_from(14), 0);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_byte_string_search() {
+	use crate::{
+		BitString,
+		ByteString,
+	};
+
+	let mut s = ByteString::from(&b"\xff\xf3"[..]);
+	s.clip(14);
+	assert_eq!(s.count_ones(), 12);
+	assert_eq!(s.leading_ones_from(4), 8);
+	assert_eq!(s.leading_zeros_from(12), 2);
+	assert_eq!(s.find_next_clear(12), Some(12));
+	assert_eq!(s.find_next_clear(14), None);
+	assert_eq!(s.find_next_set(12), None);
+}
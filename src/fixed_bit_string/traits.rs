@@ -1,4 +1,7 @@
-use crate::fixed_bit_string::Iter;
+use crate::fixed_bit_string::{
+	Iter,
+	OffsetOverflow,
+};
 
 /// A bit string with fixed length.
 ///
@@ -105,4 +108,203 @@ pub trait FixedBitString {
 	///
 	/// Should panic if `prefix > Self::LEN`.
 	fn contains(&self, prefix: usize, other: &Self) -> bool;
+
+	/// Treat bit string as integer (see [`inc`](FixedBitString::inc))
+	/// and add `n`.
+	///
+	/// Don't touch first `prefix` bits; wraps around in the remaining
+	/// bits and returns true on overflow.
+	///
+	/// # Panics
+	///
+	/// Should panic if `prefix > Self::LEN`.
+	fn add(&mut self, prefix: usize, n: u128) -> bool {
+		assert!(prefix <= Self::LEN);
+		let mut carry = false;
+		for (shift, ndx) in (prefix..Self::LEN).rev().enumerate() {
+			let addend = shift < 128 && 0 != (n >> shift) & 1;
+			if shift >= 128 && !carry {
+				break;
+			}
+			let bit = self.get(ndx);
+			self.set(ndx, bit ^ addend ^ carry);
+			carry = (bit && addend) || (carry && (bit || addend));
+		}
+		let host_bits = Self::LEN - prefix;
+		carry || (host_bits < 128 && 0 != n >> host_bits)
+	}
+
+	/// Treat bit string as integer (see [`inc`](FixedBitString::inc))
+	/// and subtract `n`.
+	///
+	/// Don't touch first `prefix` bits; wraps around in the remaining
+	/// bits and returns true on overflow.
+	///
+	/// # Panics
+	///
+	/// Should panic if `prefix > Self::LEN`.
+	fn sub(&mut self, prefix: usize, n: u128) -> bool {
+		assert!(prefix <= Self::LEN);
+		let mut borrow = false;
+		for (shift, ndx) in (prefix..Self::LEN).rev().enumerate() {
+			let subtrahend = shift < 128 && 0 != (n >> shift) & 1;
+			if shift >= 128 && !borrow {
+				break;
+			}
+			let bit = self.get(ndx);
+			self.set(ndx, bit ^ subtrahend ^ borrow);
+			borrow = (!bit && (subtrahend || borrow)) || (subtrahend && borrow);
+		}
+		let host_bits = Self::LEN - prefix;
+		borrow || (host_bits < 128 && 0 != n >> host_bits)
+	}
+
+	/// Absolute difference between `self` and `other` treated as
+	/// integers (see [`inc`](FixedBitString::inc)), ignoring the first
+	/// `prefix` bits.
+	///
+	/// Fails if the distance doesn't fit into `u128`.
+	///
+	/// # Panics
+	///
+	/// Should panic if `prefix > Self::LEN`.
+	fn distance(&self, prefix: usize, other: &Self) -> Result<u128, OffsetOverflow> {
+		assert!(prefix <= Self::LEN);
+		let Some(first_diff) = (prefix..Self::LEN).find(|&ndx| self.get(ndx) != other.get(ndx))
+		else {
+			return Ok(0);
+		};
+		let (larger, smaller) = if self.get(first_diff) {
+			(self, other)
+		} else {
+			(other, self)
+		};
+		// subtract the bits after `first_diff` (before that they are equal)
+		let mut distance: u128 = 0;
+		let mut borrow = false;
+		for (shift, ndx) in (first_diff..Self::LEN).rev().enumerate() {
+			let (a, b) = (larger.get(ndx), smaller.get(ndx));
+			if a ^ b ^ borrow {
+				if shift >= 128 {
+					return Err(OffsetOverflow);
+				}
+				distance |= 1 << shift;
+			}
+			borrow = (!a && (b || borrow)) || (b && borrow);
+		}
+		Ok(distance)
+	}
+
+	/// Number of bits set to `true`.
+	fn count_ones(&self) -> usize {
+		(0..Self::LEN).filter(|&ndx| self.get(ndx)).count()
+	}
+
+	/// Number of consecutive `false` bits starting at `ndx`.
+	///
+	/// Returns 0 if `ndx >= Self::LEN`.
+	fn leading_zeros_from(&self, ndx: usize) -> usize {
+		if ndx >= Self::LEN {
+			return 0;
+		}
+		self.find_next_set(ndx).unwrap_or(Self::LEN) - ndx
+	}
+
+	/// Number of consecutive `true` bits starting at `ndx`.
+	///
+	/// Returns 0 if `ndx >= Self::LEN`.
+	fn leading_ones_from(&self, ndx: usize) -> usize {
+		if ndx >= Self::LEN {
+			return 0;
+		}
+		self.find_next_clear(ndx).unwrap_or(Self::LEN) - ndx
+	}
+
+	/// Index of the first `true` bit in [ndx..], or `None`.
+	fn find_next_set(&self, ndx: usize) -> Option<usize> {
+		(ndx..Self::LEN).find(|&ndx| self.get(ndx))
+	}
+
+	/// Index of the first `false` bit in [ndx..], or `None`.
+	fn find_next_clear(&self, ndx: usize) -> Option<usize> {
+		(ndx..Self::LEN).find(|&ndx| !self.get(ndx))
+	}
+
+	/// Bitwise AND: set all bits to `false` that are `false` in `other`.
+	fn and_with(&mut self, other: &Self) {
+		for ndx in 0..Self::LEN {
+			if !other.get(ndx) {
+				self.set(ndx, false);
+			}
+		}
+	}
+
+	/// Bitwise OR: set all bits to `true` that are `true` in `other`.
+	fn or_with(&mut self, other: &Self) {
+		for ndx in 0..Self::LEN {
+			if other.get(ndx) {
+				self.set(ndx, true);
+			}
+		}
+	}
+
+	/// Bitwise XOR: flip all bits that are `true` in `other`.
+	fn xor_with(&mut self, other: &Self) {
+		for ndx in 0..Self::LEN {
+			if other.get(ndx) {
+				self.flip(ndx);
+			}
+		}
+	}
+
+	/// Bitwise NOT: flip all bits.
+	fn invert(&mut self) {
+		for ndx in 0..Self::LEN {
+			self.flip(ndx);
+		}
+	}
+
+	/// Offset within the prefix of the first `prefix` bits, i.e. the
+	/// bits [prefix..] as integer (where the bit with the highest index
+	/// is the least significant bit).
+	///
+	/// Fails if the offset doesn't fit into `u128`.
+	///
+	/// # Panics
+	///
+	/// Should panic if `prefix > Self::LEN`.
+	fn offset_in(&self, prefix: usize) -> Result<u128, OffsetOverflow> {
+		assert!(prefix <= Self::LEN);
+		let mut offset: u128 = 0;
+		for ndx in prefix..Self::LEN {
+			if offset.leading_zeros() == 0 {
+				return Err(OffsetOverflow);
+			}
+			offset = (offset << 1) | self.get(ndx) as u128;
+		}
+		Ok(offset)
+	}
+
+	/// Keep the first `prefix` bits and replace the bits [prefix..] with
+	/// `offset`; reverse of [`offset_in`](FixedBitString::offset_in).
+	///
+	/// Fails if `offset` doesn't fit into `Self::LEN - prefix` bits.
+	///
+	/// # Panics
+	///
+	/// Should panic if `prefix > Self::LEN`.
+	fn with_offset(mut self, prefix: usize, offset: u128) -> Result<Self, OffsetOverflow>
+	where
+		Self: Sized,
+	{
+		assert!(prefix <= Self::LEN);
+		let host_bits = Self::LEN - prefix;
+		if host_bits < 128 && offset >> host_bits != 0 {
+			return Err(OffsetOverflow);
+		}
+		for (shift, ndx) in (prefix..Self::LEN).rev().enumerate() {
+			self.set(ndx, shift < 128 && 0 != (offset >> shift) & 1);
+		}
+		Ok(self)
+	}
 }
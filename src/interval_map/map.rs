@@ -0,0 +1,272 @@
+use alloc::collections::{
+	btree_map,
+	BTreeMap,
+};
+use core::{
+	cmp::Ordering,
+	fmt,
+	ops::RangeInclusive,
+};
+
+use crate::{
+	interval_map::{
+		range_prefixes::{
+			cmp_bits,
+			predecessor,
+			successor,
+		},
+		RangePrefixes,
+	},
+	BitLengthString,
+	FixedBitString,
+};
+
+/// Wrapper to order fixed bit strings as big endian integers
+#[derive(Clone)]
+pub(crate) struct Key<W>(W);
+
+impl<W: FixedBitString> PartialEq for Key<W> {
+	fn eq(&self, other: &Self) -> bool {
+		self.cmp(other).is_eq()
+	}
+}
+
+impl<W: FixedBitString> Eq for Key<W> {}
+
+impl<W: FixedBitString> PartialOrd for Key<W> {
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+impl<W: FixedBitString> Ord for Key<W> {
+	fn cmp(&self, other: &Self) -> Ordering {
+		cmp_bits(&self.0, &other.0)
+	}
+}
+
+/// Map from ranges of [`FixedBitString`] values to values.
+///
+/// Values are compared as big endian integers (bit 0 is the most
+/// significant bit).
+///
+/// Inserting a range overwrites the values of all overlapping ranges;
+/// adjacent ranges with equal values are merged.
+#[derive(Clone)]
+pub struct IntervalMap<W, V> {
+	/// map from start to end and value
+	ranges: BTreeMap<Key<W>, (W, V)>,
+}
+
+impl<W, V> IntervalMap<W, V> {
+	/// Create new empty map
+	pub const fn new() -> Self {
+		IntervalMap {
+			ranges: BTreeMap::new(),
+		}
+	}
+
+	/// Number of (merged) ranges in the map
+	pub fn len(&self) -> usize {
+		self.ranges.len()
+	}
+
+	/// Whether the map is empty
+	pub fn is_empty(&self) -> bool {
+		self.ranges.is_empty()
+	}
+
+	/// Remove all ranges
+	pub fn clear(&mut self) {
+		self.ranges.clear();
+	}
+
+	/// Iterate over all ranges as `(start, end, value)` in ascending
+	/// order
+	pub fn iter(&self) -> Iter<'_, W, V> {
+		Iter {
+			inner: self.ranges.iter(),
+		}
+	}
+}
+
+impl<W: FixedBitString + Clone, V> IntervalMap<W, V> {
+	/// Find range containing `value`; returns `(start, end, value)`
+	pub fn get_range(&self, value: &W) -> Option<(&W, &W, &V)> {
+		let (start, (end, value_)) = self.ranges.range(..=Key(value.clone())).next_back()?;
+		if cmp_bits(end, value).is_lt() {
+			return None;
+		}
+		Some((&start.0, end, value_))
+	}
+
+	/// Find value for range containing `value`
+	pub fn get(&self, value: &W) -> Option<&V> {
+		Some(self.get_range(value)?.2)
+	}
+
+	/// Iterate over the minimal list of prefixes covering the ranges;
+	/// yields prefixes in ascending order.
+	pub fn prefixes(&self) -> Prefixes<'_, W, V> {
+		Prefixes {
+			ranges: self.ranges.iter(),
+			current: None,
+		}
+	}
+}
+
+impl<W: FixedBitString + Clone, V: Clone + PartialEq> IntervalMap<W, V> {
+	/// Remove all values in `start..=end` from existing ranges
+	fn cut(&mut self, start: &W, end: &W) {
+		loop {
+			let Some((first, (last, _))) = self.ranges.range(..=Key(end.clone())).next_back()
+			else {
+				return;
+			};
+			if cmp_bits(last, start).is_lt() {
+				return;
+			}
+			let first = first.clone();
+			let (last, value) = self.ranges.remove(&first).expect("just found");
+			if cmp_bits(&last, end).is_gt() {
+				let after = successor(end).expect("less than last");
+				self.ranges.insert(Key(after), (last, value.clone()));
+			}
+			if cmp_bits(&first.0, start).is_lt() {
+				// loop will stop at this range next
+				let before = predecessor(start).expect("greater than first");
+				self.ranges.insert(first, (before, value));
+			}
+		}
+	}
+
+	/// Set value for all values in `range`; merges with adjacent ranges
+	/// of equal value.
+	///
+	/// # Panics
+	///
+	/// Panics if the range is empty (`start > end`).
+	pub fn insert(&mut self, range: RangeInclusive<W>, value: V) {
+		let (mut start, mut end) = range.into_inner();
+		assert!(cmp_bits(&start, &end).is_le(), "empty range");
+		self.cut(&start, &end);
+
+		if let Some(before) = predecessor(&start) {
+			if let Some((first, (last, prev_value))) =
+				self.ranges.range(..Key(start.clone())).next_back()
+			{
+				if cmp_bits(last, &before).is_eq() && *prev_value == value {
+					let first = first.clone();
+					self.ranges.remove(&first);
+					start = first.0;
+				}
+			}
+		}
+		if let Some(after) = successor(&end) {
+			let after = Key(after);
+			if let Some((_, next_value)) = self.ranges.get(&after) {
+				if *next_value == value {
+					let (last, _) = self.ranges.remove(&after).expect("just found");
+					end = last;
+				}
+			}
+		}
+		self.ranges.insert(Key(start), (end, value));
+	}
+
+	/// Remove all values in `range`.
+	///
+	/// Does nothing if the range is empty (`start > end`).
+	pub fn remove(&mut self, range: RangeInclusive<W>) {
+		let (start, end) = range.into_inner();
+		if cmp_bits(&start, &end).is_le() {
+			self.cut(&start, &end);
+		}
+	}
+}
+
+impl<W, V> Default for IntervalMap<W, V> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<W: fmt::Debug, V: fmt::Debug> fmt::Debug for IntervalMap<W, V> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_map()
+			.entries(self.iter().map(|(start, end, value)| (start..=end, value)))
+			.finish()
+	}
+}
+
+impl<W, V> FromIterator<(RangeInclusive<W>, V)> for IntervalMap<W, V>
+where
+	W: FixedBitString + Clone,
+	V: Clone + PartialEq,
+{
+	fn from_iter<I: IntoIterator<Item = (RangeInclusive<W>, V)>>(iter: I) -> Self {
+		let mut map = Self::new();
+		map.extend(iter);
+		map
+	}
+}
+
+impl<W, V> Extend<(RangeInclusive<W>, V)> for IntervalMap<W, V>
+where
+	W: FixedBitString + Clone,
+	V: Clone + PartialEq,
+{
+	fn extend<I: IntoIterator<Item = (RangeInclusive<W>, V)>>(&mut self, iter: I) {
+		for (range, value) in iter {
+			self.insert(range, value);
+		}
+	}
+}
+
+/// Iterator over all ranges of an [`IntervalMap`].
+///
+/// Created by [`IntervalMap::iter()`].
+#[derive(Clone)]
+pub struct Iter<'a, W, V> {
+	inner: btree_map::Iter<'a, Key<W>, (W, V)>,
+}
+
+impl<'a, W, V> Iterator for Iter<'a, W, V> {
+	type Item = (&'a W, &'a W, &'a V);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let (start, (end, value)) = self.inner.next()?;
+		Some((&start.0, end, value))
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.inner.size_hint()
+	}
+}
+
+impl<W, V> ExactSizeIterator for Iter<'_, W, V> {}
+
+/// Iterator over the prefixes covering the ranges of an
+/// [`IntervalMap`].
+///
+/// Created by [`IntervalMap::prefixes()`].
+pub struct Prefixes<'a, W, V> {
+	ranges: btree_map::Iter<'a, Key<W>, (W, V)>,
+	current: Option<(RangePrefixes<W>, &'a V)>,
+}
+
+impl<'a, W: FixedBitString + Clone, V> Iterator for Prefixes<'a, W, V> {
+	type Item = (BitLengthString<W>, &'a V);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		loop {
+			if let Some((prefixes, value)) = &mut self.current {
+				if let Some(prefix) = prefixes.next() {
+					return Some((prefix, value));
+				}
+			}
+			let (start, (end, value)) = self.ranges.next()?;
+			self.current = Some((RangePrefixes::new(start.0.clone(), end.clone()), value));
+		}
+	}
+}
@@ -0,0 +1,16 @@
+//! contains extra types related to [`IntervalMap`](crate::IntervalMap).
+
+pub use self::{
+	map::{
+		Iter,
+		Prefixes,
+	},
+	range_prefixes::RangePrefixes,
+};
+
+pub(crate) mod map;
+pub(crate) mod range_prefixes;
+
+#[cfg(feature = "net")]
+#[cfg(test)]
+mod tests;
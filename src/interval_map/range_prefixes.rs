@@ -0,0 +1,99 @@
+use core::cmp::Ordering;
+
+use crate::{
+	BitLengthString,
+	FixedBitString,
+};
+
+/// Compare two fixed bit strings as big endian integers
+pub(crate) fn cmp_bits<W: FixedBitString>(a: &W, b: &W) -> Ordering {
+	let spl = a.shared_prefix_len(b);
+	if spl == W::LEN {
+		Ordering::Equal
+	} else if a.get(spl) {
+		Ordering::Greater
+	} else {
+		Ordering::Less
+	}
+}
+
+/// Smallest `ndx` with `value.is_false_from(ndx)`
+pub(crate) fn false_from<W: FixedBitString>(value: &W) -> usize {
+	let (mut lo, mut hi) = (0, W::LEN);
+	while lo < hi {
+		let mid = (lo + hi) / 2;
+		if value.is_false_from(mid) {
+			hi = mid;
+		} else {
+			lo = mid + 1;
+		}
+	}
+	lo
+}
+
+/// Next value (as big endian integer); `None` on overflow
+pub(crate) fn successor<W: FixedBitString + Clone>(value: &W) -> Option<W> {
+	let mut value = value.clone();
+	if value.inc(0) {
+		None
+	} else {
+		Some(value)
+	}
+}
+
+/// Previous value (as big endian integer); `None` on underflow
+pub(crate) fn predecessor<W: FixedBitString + Clone>(value: &W) -> Option<W> {
+	let ndx = false_from(value);
+	if 0 == ndx {
+		return None;
+	}
+	let mut value = value.clone();
+	value.set(ndx - 1, false);
+	value.set_true_from(ndx);
+	Some(value)
+}
+
+/// Iterator over the minimal list of prefixes covering exactly the
+/// values in a range `start..=end` (comparing values as big endian
+/// integers).
+///
+/// Yields the prefixes in ascending order.
+#[derive(Clone, Debug)]
+pub struct RangePrefixes<W> {
+	next: Option<(W, W)>,
+}
+
+impl<W: FixedBitString + Clone> RangePrefixes<W> {
+	/// Create iterator for range `start..=end`; the range is empty if
+	/// `start > end`.
+	pub fn new(start: W, end: W) -> Self {
+		let next = if cmp_bits(&start, &end).is_gt() {
+			None
+		} else {
+			Some((start, end))
+		};
+		RangePrefixes { next }
+	}
+}
+
+impl<W: FixedBitString + Clone> Iterator for RangePrefixes<W> {
+	type Item = BitLengthString<W>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let (start, end) = self.next.take()?;
+		// largest aligned block starting at `start` not exceeding `end`
+		let mut len = false_from(&start);
+		let last = loop {
+			let mut last = start.clone();
+			last.set_true_from(len);
+			if cmp_bits(&last, &end).is_le() {
+				break last;
+			}
+			len += 1;
+		};
+		if cmp_bits(&last, &end).is_lt() {
+			self.next = Some((successor(&last).expect("less than end"), end));
+		}
+		Some(BitLengthString::new(start, len))
+	}
+}
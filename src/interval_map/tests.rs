@@ -0,0 +1,101 @@
+use alloc::vec::Vec;
+use core::net::Ipv4Addr;
+
+use crate::{
+	interval_map::RangePrefixes,
+	test_utils::lcg,
+	BitLengthString,
+	FixedBitString,
+	IntervalMap,
+};
+
+fn a(n: u32) -> Ipv4Addr {
+	Ipv4Addr::from_bits(n)
+}
+
+#[test]
+fn test_range_prefixes() {
+	let p = |addr: [u8; 4], len| BitLengthString::new(Ipv4Addr::from(addr), len);
+	let prefixes: Vec<_> =
+		RangePrefixes::new(Ipv4Addr::new(10, 0, 0, 1), Ipv4Addr::new(10, 0, 1, 128)).collect();
+	assert_eq!(
+		prefixes,
+		[
+			p([10, 0, 0, 1], 32),
+			p([10, 0, 0, 2], 31),
+			p([10, 0, 0, 4], 30),
+			p([10, 0, 0, 8], 29),
+			p([10, 0, 0, 16], 28),
+			p([10, 0, 0, 32], 27),
+			p([10, 0, 0, 64], 26),
+			p([10, 0, 0, 128], 25),
+			p([10, 0, 1, 0], 25),
+			p([10, 0, 1, 128], 32),
+		]
+	);
+	let all: Vec<_> = RangePrefixes::new(a(0), a(!0)).collect();
+	assert_eq!(all, [p([0, 0, 0, 0], 0)]);
+	assert_eq!(RangePrefixes::new(a(2), a(1)).count(), 0);
+}
+
+#[test]
+fn test_insert_merge() {
+	let mut map = IntervalMap::new();
+	map.insert(a(10)..=a(19), 1);
+	map.insert(a(30)..=a(39), 1);
+	assert_eq!(map.len(), 2);
+	map.insert(a(20)..=a(29), 1);
+	assert_eq!(map.len(), 1);
+	map.insert(a(15)..=a(16), 2);
+	assert_eq!(map.len(), 3);
+	assert_eq!(map.get(&a(14)), Some(&1));
+	assert_eq!(map.get(&a(15)), Some(&2));
+	assert_eq!(map.get(&a(17)), Some(&1));
+	assert_eq!(map.get(&a(40)), None);
+	map.insert(a(0)..=a(!0), 3);
+	assert_eq!(map.len(), 1);
+	assert_eq!(map.get_range(&a(1)), Some((&a(0), &a(!0), &3)));
+	map.remove(a(0)..=a(0));
+	map.remove(a(!0)..=a(!0));
+	assert_eq!(map.get_range(&a(1)), Some((&a(1), &a(!0 - 1), &3)));
+}
+
+#[test]
+fn test_random() {
+	let mut lcg = lcg(0x1357_9bdf);
+	let mut next = move || lcg() >> 8;
+	let mut map = IntervalMap::new();
+	let mut reference = [None; 256];
+	for _ in 0..500 {
+		let (x, y) = (next() % 256, next() % 256);
+		let (start, end) = (x.min(y), x.max(y));
+		let value = if next() % 5 == 0 {
+			None
+		} else {
+			Some(next() % 3)
+		};
+		match value {
+			Some(value) => map.insert(a(start)..=a(end), value),
+			None => map.remove(a(start)..=a(end)),
+		}
+		reference[start as usize..=end as usize].fill(value);
+
+		for (n, expected) in reference.iter().enumerate() {
+			assert_eq!(map.get(&a(n as u32)), expected.as_ref());
+		}
+		// adjacent ranges have different values
+		let ranges: Vec<_> = map.iter().collect();
+		for pair in ranges.windows(2) {
+			let ((_, end, v1), (start, _, v2)) = (pair[0], pair[1]);
+			assert!(end.to_bits() + 1 < start.to_bits() || v1 != v2);
+		}
+		// prefixes cover exactly the same values
+		let mut covered = [None; 256];
+		for (prefix, &value) in map.prefixes() {
+			for addr in prefix.bits().iter(prefix.len()) {
+				assert!(covered[addr.to_bits() as usize].replace(value).is_none());
+			}
+		}
+		assert_eq!(covered, reference);
+	}
+}
@@ -2,9 +2,37 @@
 //!
 //! ## Features
 //!
-//! - `fixed`: enables [`FixedBitString`] and [`fixed_bit_string`]
+//! - `fixed`: enables [`FixedBitString`], [`fixed_bit_string`] and
+//!   [`BigEndianBytes`]
 //! - `bigendian`: enables [`utils::BigEndianBitString`] and [`utils::bigendian`]
 //! - `net`: implements [`FixedBitString`] for [`Ipv4Addr`] and [`Ipv6Addr`]
+//! - `alloc`: enables [`ByteString`], [`ByteStr`], [`PrefixMap`],
+//!   [`PersistentPrefixMap`] and [`prefix_map`]; [`ByteStr`] is only a
+//!   read-only view and doesn't implement [`BitString`]
+//! - `alloc` + `fixed`: enables [`IntervalMap`], [`interval_map`],
+//!   [`classifier`], [`coverage`] and [`lpm`] (IPv4 and IPv6 tables
+//!   also need `net`)
+//! - `alloc` + `net`: enables [`der::rfc3779`]
+//! - `serde`: with `fixed` implements `Serialize` and `Deserialize` for
+//!   [`BitLengthString`] and [`BigEndianBytes`] (and with `alloc` + `net`
+//!   for [`der::rfc3779::IpAddressOrRange`]); human-readable formats use
+//!   `addr/len` for addresses and the hex notation of [`text`] otherwise,
+//!   others the prefix length followed by the bytes covered by the prefix
+//! - `arbitrary`, `proptest`: with `fixed` enable [`testing`] to generate
+//!   bit strings for fuzzing and property tests (`proptest` also enables
+//!   `std`)
+//! - `ipnet`, `ipnetwork`, `cidr`: enable [`convert`] with conversions
+//!   between [`BitLengthString`] of [`Ipv4Addr`] and [`Ipv6Addr`] and the
+//!   network types of these crates (`ipnetwork` and `cidr` also enable
+//!   `std`)
+//! - `bitvec`: implements [`BitString`] for `BitVec`, [`FixedBitString`]
+//!   for `BitArray` and conversions between `BitVec`/`BitSlice` and
+//!   [`BitLengthString`] (see [`convert`]; also enables `alloc` and
+//!   `fixed`)
+//! - `zerocopy`, `bytemuck`: implement the traits of these crates for
+//!   [`BigEndianBytes`] (also enable `fixed`)
+//! - `std`: enables `alloc`; with `fixed` also the concurrent table in
+//!   [`lpm`]
 //!
 //! [`Ipv4Addr`]: core::net::Ipv4Addr
 //! [`Ipv6Addr`]: core::net::Ipv6Addr
@@ -13,22 +41,81 @@
 #![no_std]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
 pub use self::bit_string::BitString;
 
+#[cfg(feature = "alloc")]
+pub use self::byte_string::{
+	borrowed::ByteStr,
+	owned::ByteString,
+};
+
+#[cfg(all(feature = "alloc", feature = "fixed"))]
+pub use self::interval_map::map::IntervalMap;
+#[cfg(feature = "alloc")]
+pub use self::prefix_map::{
+	map::PrefixMap,
+	persistent::PersistentPrefixMap,
+};
+
 #[cfg(feature = "fixed")]
 pub use self::{
+	big_endian_bytes::BigEndianBytes,
 	bit_length_string::BitLengthString,
 	fixed_bit_string::traits::FixedBitString,
 };
 
 mod bit_string;
+#[cfg(feature = "alloc")]
+mod byte_string;
+
+pub mod der;
+pub mod diff;
+
+pub mod text;
+
+#[cfg(all(feature = "fixed", any(feature = "arbitrary", feature = "proptest")))]
+pub mod testing;
 
 #[cfg(feature = "net")]
 mod address;
+#[cfg(feature = "bitvec")]
+mod bitvec_impl;
+#[cfg(any(
+	feature = "ipnet",
+	feature = "ipnetwork",
+	feature = "cidr",
+	feature = "bitvec"
+))]
+pub mod convert;
+#[cfg(all(feature = "fixed", feature = "serde"))]
+mod serde_impl;
 
 pub mod utils;
 
+#[cfg(all(feature = "alloc", feature = "net"))]
+#[cfg(test)]
+mod test_utils;
+
+#[cfg(feature = "fixed")]
+mod big_endian_bytes;
 #[cfg(feature = "fixed")]
 mod bit_length_string;
 #[cfg(feature = "fixed")]
 pub mod fixed_bit_string;
+
+#[cfg(feature = "alloc")]
+pub mod prefix_map;
+
+#[cfg(all(feature = "alloc", feature = "fixed"))]
+pub mod classifier;
+#[cfg(all(feature = "alloc", feature = "fixed"))]
+pub mod coverage;
+#[cfg(all(feature = "alloc", feature = "fixed"))]
+pub mod interval_map;
+#[cfg(all(feature = "alloc", feature = "fixed"))]
+pub mod lpm;
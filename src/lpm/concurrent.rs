@@ -0,0 +1,234 @@
+use alloc::{
+	boxed::Box,
+	sync::Arc,
+	vec::Vec,
+};
+use core::{
+	marker::PhantomData,
+	sync::atomic::{
+		AtomicPtr,
+		AtomicU64,
+		Ordering,
+	},
+};
+use std::sync::Mutex;
+
+use crate::{
+	BitLengthString,
+	FixedBitString,
+	PersistentPrefixMap,
+};
+
+type Routes<W, V> = PersistentPrefixMap<BitLengthString<W>, V>;
+
+/// Reader epoch of a reader outside a read section
+const INACTIVE: u64 = 0;
+
+struct ReaderSlot {
+	/// [`INACTIVE`] or global epoch when read section was entered
+	epoch: AtomicU64,
+}
+
+struct Shared<W: FixedBitString, V> {
+	/// current version; created by `Box::into_raw`
+	current: AtomicPtr<Routes<W, V>>,
+	/// incremented after each published version; starts at 1
+	epoch: AtomicU64,
+	readers: Mutex<Vec<Arc<ReaderSlot>>>,
+	/// behave like owning the versions regarding `Send` and `Sync`
+	_marker: PhantomData<Arc<Routes<W, V>>>,
+}
+
+impl<W: FixedBitString, V> Drop for Shared<W, V> {
+	fn drop(&mut self) {
+		// SAFETY: created by `Box::into_raw`; no readers left
+		drop(unsafe { Box::from_raw(*self.current.get_mut()) });
+	}
+}
+
+/// Longest-prefix-match table for concurrent lookups while routes are
+/// updated.
+///
+/// Readers ([`LpmReader`]) never block: the table publishes immutable
+/// versions by swapping a pointer, readers announce the epoch they
+/// started reading in (RCU style).  The writer waits until no reader
+/// can still see an old version before freeing it.
+///
+/// Versions are [`PersistentPrefixMap`]s, so updates only copy the
+/// modified paths of the trie.
+pub struct ConcurrentLpmTable<W: FixedBitString, V> {
+	shared: Arc<Shared<W, V>>,
+	/// copy of the current version
+	routes: Routes<W, V>,
+}
+
+impl<W, V> ConcurrentLpmTable<W, V>
+where
+	W: FixedBitString + Clone,
+	V: Clone,
+{
+	/// Create table with initial routes
+	pub fn new(routes: Routes<W, V>) -> Self {
+		let shared = Shared {
+			current: AtomicPtr::new(Box::into_raw(Box::new(routes.clone()))),
+			epoch: AtomicU64::new(1),
+			readers: Mutex::new(Vec::new()),
+			_marker: PhantomData,
+		};
+		ConcurrentLpmTable {
+			shared: Arc::new(shared),
+			routes,
+		}
+	}
+
+	/// Current routes
+	pub fn routes(&self) -> &Routes<W, V> {
+		&self.routes
+	}
+
+	/// Create new reader handle
+	pub fn reader(&self) -> LpmReader<W, V> {
+		LpmReader::register(self.shared.clone())
+	}
+
+	/// Apply a batch of updates and publish the result as new version.
+	///
+	/// Waits until all readers that might still use the old version
+	/// left their read section (but doesn't block new readers).
+	pub fn update<F, T>(&mut self, f: F) -> T
+	where
+		F: FnOnce(&mut Routes<W, V>) -> T,
+	{
+		let result = f(&mut self.routes);
+		self.publish();
+		result
+	}
+
+	/// Insert single route; see [`update`](Self::update) for batches.
+	pub fn insert(&mut self, prefix: BitLengthString<W>, value: V) -> Option<V> {
+		self.update(|routes| routes.insert(prefix, value))
+	}
+
+	/// Remove single route; see [`update`](Self::update) for batches.
+	pub fn remove(&mut self, prefix: &BitLengthString<W>) -> Option<V> {
+		self.update(|routes| routes.remove(prefix))
+	}
+
+	fn publish(&mut self) {
+		let shared = &*self.shared;
+		let new = Box::into_raw(Box::new(self.routes.clone()));
+		let old = shared.current.swap(new, Ordering::SeqCst);
+		// readers announcing this (or a later) epoch will see the new
+		// version
+		let epoch = shared.epoch.fetch_add(1, Ordering::SeqCst) + 1;
+		let readers = shared.readers.lock().unwrap_or_else(|e| e.into_inner());
+		for reader in readers.iter() {
+			loop {
+				let reader_epoch = reader.epoch.load(Ordering::SeqCst);
+				if INACTIVE == reader_epoch || reader_epoch >= epoch {
+					break;
+				}
+				std::thread::yield_now();
+			}
+		}
+		drop(readers);
+		// SAFETY: created by `Box::into_raw`; not reachable anymore
+		// through `current`, and all readers that might have loaded it
+		// left their read section.
+		drop(unsafe { Box::from_raw(old) });
+	}
+}
+
+impl<W: FixedBitString + Clone, V: Clone> Default for ConcurrentLpmTable<W, V> {
+	fn default() -> Self {
+		Self::new(PersistentPrefixMap::new())
+	}
+}
+
+/// Reader handle for a [`ConcurrentLpmTable`].
+///
+/// Each thread needs its own handle; clone it for other threads.
+pub struct LpmReader<W: FixedBitString, V> {
+	shared: Arc<Shared<W, V>>,
+	slot: Arc<ReaderSlot>,
+}
+
+impl<W: FixedBitString, V> LpmReader<W, V> {
+	fn register(shared: Arc<Shared<W, V>>) -> Self {
+		let slot = Arc::new(ReaderSlot {
+			epoch: AtomicU64::new(INACTIVE),
+		});
+		shared
+			.readers
+			.lock()
+			.unwrap_or_else(|e| e.into_inner())
+			.push(slot.clone());
+		LpmReader { shared, slot }
+	}
+
+	/// Access current version of the routes; never blocks.
+	///
+	/// The writer can't free the version while `f` runs, so keep it
+	/// short.
+	pub fn read<F, T>(&mut self, f: F) -> T
+	where
+		F: FnOnce(&Routes<W, V>) -> T,
+	{
+		let shared = &*self.shared;
+		self.slot
+			.epoch
+			.store(shared.epoch.load(Ordering::SeqCst), Ordering::SeqCst);
+		let current = shared.current.load(Ordering::SeqCst);
+		// SAFETY: the writer doesn't free versions a reader might have
+		// loaded after announcing its epoch; `&mut self` prevents nested
+		// read sections resetting the announcement.
+		let result = f(unsafe { &*current });
+		self.slot.epoch.store(INACTIVE, Ordering::Release);
+		result
+	}
+
+	/// Find the longest route containing `addr`
+	pub fn longest_match(&mut self, addr: &W) -> Option<(BitLengthString<W>, V)>
+	where
+		W: Clone,
+		V: Clone,
+	{
+		let key = BitLengthString::new(addr.clone(), W::LEN);
+		self.read(|routes| {
+			let (prefix, value) = routes.longest_match(&key)?;
+			Some((prefix.clone(), value.clone()))
+		})
+	}
+
+	/// Find value of the longest route containing `addr`
+	pub fn lookup(&mut self, addr: &W) -> Option<V>
+	where
+		W: Clone,
+		V: Clone,
+	{
+		let key = BitLengthString::new(addr.clone(), W::LEN);
+		self.read(|routes| Some(routes.longest_match(&key)?.1.clone()))
+	}
+
+	/// Get a snapshot of the current version that can be kept
+	pub fn snapshot(&mut self) -> Routes<W, V> {
+		self.read(Clone::clone)
+	}
+}
+
+impl<W: FixedBitString, V> Clone for LpmReader<W, V> {
+	fn clone(&self) -> Self {
+		Self::register(self.shared.clone())
+	}
+}
+
+impl<W: FixedBitString, V> Drop for LpmReader<W, V> {
+	fn drop(&mut self) {
+		let mut readers = self
+			.shared
+			.readers
+			.lock()
+			.unwrap_or_else(|e| e.into_inner());
+		readers.retain(|slot| !Arc::ptr_eq(slot, &self.slot));
+	}
+}
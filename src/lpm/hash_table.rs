@@ -0,0 +1,118 @@
+use alloc::vec::Vec;
+use core::hash::{
+	Hash,
+	Hasher,
+};
+
+/// Simple multiplicative hasher (similar to `FxHasher`)
+struct SimpleHasher {
+	hash: u64,
+}
+
+impl SimpleHasher {
+	const SEED: u64 = 0x51_7c_c1_b7_27_22_0a_95;
+
+	fn add(&mut self, word: u64) {
+		self.hash = (self.hash.rotate_left(5) ^ word).wrapping_mul(Self::SEED);
+	}
+}
+
+impl Hasher for SimpleHasher {
+	fn write(&mut self, bytes: &[u8]) {
+		for chunk in bytes.chunks(8) {
+			let mut buf = [0u8; 8];
+			buf[..chunk.len()].copy_from_slice(chunk);
+			self.add(u64::from_le_bytes(buf));
+		}
+	}
+
+	fn write_u8(&mut self, i: u8) {
+		self.add(i as u64);
+	}
+
+	fn write_u16(&mut self, i: u16) {
+		self.add(i as u64);
+	}
+
+	fn write_u32(&mut self, i: u32) {
+		self.add(i as u64);
+	}
+
+	fn write_u64(&mut self, i: u64) {
+		self.add(i);
+	}
+
+	fn write_u128(&mut self, i: u128) {
+		self.add(i as u64);
+		self.add((i >> 64) as u64);
+	}
+
+	fn write_usize(&mut self, i: usize) {
+		self.add(i as u64);
+	}
+
+	fn finish(&self) -> u64 {
+		self.hash
+	}
+}
+
+/// Fixed size hash table with open addressing (linear probing).
+///
+/// Size must be known when creating the table; it is never resized.
+pub(crate) struct HashTable<K, T> {
+	slots: Vec<Option<(K, T)>>,
+	/// log2 of `slots.len()`
+	bits: u32,
+}
+
+impl<K: Hash + Eq, T> HashTable<K, T> {
+	/// New table with room for `capacity` entries (load factor is at
+	/// most 50%).
+	pub(crate) fn with_capacity(capacity: usize) -> Self {
+		let bits = (2 * capacity).next_power_of_two().trailing_zeros().max(1);
+		let mut slots = Vec::new();
+		slots.resize_with(1 << bits, || None);
+		HashTable { slots, bits }
+	}
+
+	fn start(&self, key: &K) -> usize {
+		let mut hasher = SimpleHasher { hash: 0 };
+		key.hash(&mut hasher);
+		// high bits of a multiplicative hash are mixed best
+		(hasher.finish() >> (64 - self.bits)) as usize
+	}
+
+	/// Insert new entry.
+	///
+	/// # Panics
+	///
+	/// Might panic if more than `capacity` entries are inserted or the
+	/// key was already present.
+	pub(crate) fn insert(&mut self, key: K, value: T) {
+		let mask = self.slots.len() - 1;
+		let mut ndx = self.start(&key);
+		loop {
+			match &mut self.slots[ndx] {
+				slot @ None => {
+					*slot = Some((key, value));
+					return;
+				},
+				Some((slot_key, _)) => assert!(*slot_key != key, "duplicate key"),
+			}
+			ndx = (ndx + 1) & mask;
+		}
+	}
+
+	pub(crate) fn get(&self, key: &K) -> Option<&T> {
+		let mask = self.slots.len() - 1;
+		let mut ndx = self.start(key);
+		loop {
+			match &self.slots[ndx] {
+				None => return None,
+				Some((slot_key, value)) if slot_key == key => return Some(value),
+				Some(_) => (),
+			}
+			ndx = (ndx + 1) & mask;
+		}
+	}
+}
@@ -0,0 +1,194 @@
+use alloc::{
+	vec,
+	vec::Vec,
+};
+use core::{
+	fmt,
+	net::Ipv4Addr,
+};
+
+use crate::{
+	BitLengthString,
+	PrefixMap,
+};
+
+/// `tbl24` / `tbl8` entry: no matching route
+const EMPTY: u32 = 0;
+/// `tbl24` entry: remaining bits are the index of a `tbl8` group
+const GROUP_FLAG: u32 = 1 << 31;
+
+/// Bits looked up in the first level
+const TBL24_BITS: usize = 24;
+/// Entries in a second level group
+const GROUP_SIZE: usize = 1 << (32 - TBL24_BITS);
+
+/// Compiled longest-prefix-match table for [`Ipv4Addr`] keys.
+///
+/// Uses the DIR-24-8 layout: a direct-indexed first level for the
+/// first 24 bits, and groups of 256 entries for the last 8 bits if
+/// routes longer than 24 bits exist for the `/24` prefix.  Entries only
+/// store a route index, so each lookup needs at most two table accesses
+/// plus one to load the route, independent of the size of `V`.
+///
+/// The first level always takes 64 MiB; each `/24` prefix with longer
+/// routes adds a group of 1 KiB.
+pub struct Ipv4LpmTable<V> {
+	routes: Vec<(BitLengthString<Ipv4Addr>, V)>,
+	/// route index + 1, or group index with [`GROUP_FLAG`]
+	tbl24: Vec<u32>,
+	/// route index + 1
+	tbl8: Vec<u32>,
+}
+
+impl<V> Ipv4LpmTable<V> {
+	/// Build table from routes.
+	///
+	/// If a prefix is present multiple times the last value wins.
+	///
+	/// # Panics
+	///
+	/// Panics if there are `2^31` or more routes.
+	pub fn new<I>(routes: I) -> Self
+	where
+		I: IntoIterator<Item = (BitLengthString<Ipv4Addr>, V)>,
+	{
+		Self::from(routes.into_iter().collect::<PrefixMap<_, _>>())
+	}
+
+	/// Number of (distinct) routes in the table
+	pub fn len(&self) -> usize {
+		self.routes.len()
+	}
+
+	/// Whether the table has no routes
+	pub fn is_empty(&self) -> bool {
+		self.routes.is_empty()
+	}
+
+	/// Iterate over all routes in
+	/// [`lexicographic_cmp`](crate::BitString::lexicographic_cmp) order
+	pub fn routes(&self) -> impl ExactSizeIterator<Item = (&BitLengthString<Ipv4Addr>, &V)> {
+		self.routes.iter().map(|(prefix, value)| (prefix, value))
+	}
+
+	#[inline]
+	fn entry(&self, addr: u32) -> u32 {
+		let entry = self.tbl24[(addr >> (32 - TBL24_BITS)) as usize];
+		if 0 != entry & GROUP_FLAG {
+			self.group_entry(entry, addr)
+		} else {
+			entry
+		}
+	}
+
+	#[inline]
+	fn group_entry(&self, entry: u32, addr: u32) -> u32 {
+		let group = (entry & !GROUP_FLAG) as usize;
+		self.tbl8[group * GROUP_SIZE + (addr as usize % GROUP_SIZE)]
+	}
+
+	#[inline]
+	fn route(&self, entry: u32) -> Option<&(BitLengthString<Ipv4Addr>, V)> {
+		if EMPTY == entry {
+			None
+		} else {
+			Some(&self.routes[entry as usize - 1])
+		}
+	}
+
+	/// Find value of the longest route containing `addr`
+	pub fn lookup(&self, addr: Ipv4Addr) -> Option<&V> {
+		Some(&self.route(self.entry(addr.to_bits()))?.1)
+	}
+
+	/// Find the longest route containing `addr`
+	pub fn lookup_route(&self, addr: Ipv4Addr) -> Option<(&BitLengthString<Ipv4Addr>, &V)> {
+		let (prefix, value) = self.route(self.entry(addr.to_bits()))?;
+		Some((prefix, value))
+	}
+
+	/// Lookup many addresses at once; stores the result for `addrs[i]`
+	/// in `results[i]`.
+	///
+	/// Processes addresses in small batches, first loading all first
+	/// level entries of a batch, so the memory accesses can overlap.
+	///
+	/// # Panics
+	///
+	/// Panics if `addrs.len() != results.len()`.
+	pub fn lookup_batch<'a>(&'a self, addrs: &[Ipv4Addr], results: &mut [Option<&'a V>]) {
+		const BATCH: usize = 8;
+
+		assert_eq!(addrs.len(), results.len());
+		for (addrs, results) in addrs.chunks(BATCH).zip(results.chunks_mut(BATCH)) {
+			let mut entries = [EMPTY; BATCH];
+			for (entry, addr) in entries.iter_mut().zip(addrs) {
+				*entry = self.tbl24[(addr.to_bits() >> (32 - TBL24_BITS)) as usize];
+			}
+			for ((result, &entry), addr) in results.iter_mut().zip(&entries).zip(addrs) {
+				let entry = if 0 != entry & GROUP_FLAG {
+					self.group_entry(entry, addr.to_bits())
+				} else {
+					entry
+				};
+				*result = self.route(entry).map(|(_, value)| value);
+			}
+		}
+	}
+}
+
+impl<V> From<PrefixMap<BitLengthString<Ipv4Addr>, V>> for Ipv4LpmTable<V> {
+	/// Build table from routes in a [`PrefixMap`].
+	///
+	/// # Panics
+	///
+	/// Panics if there are `2^31` or more routes.
+	fn from(map: PrefixMap<BitLengthString<Ipv4Addr>, V>) -> Self {
+		assert!(map.len() < GROUP_FLAG as usize);
+		let mut tbl24 = vec![EMPTY; 1 << TBL24_BITS];
+		let mut tbl8 = Vec::new();
+		let mut routes = Vec::with_capacity(map.len());
+		// iteration visits shorter prefixes before longer prefixes they
+		// contain; longer prefixes overwrite entries of shorter ones.
+		for (prefix, value) in map {
+			routes.push((prefix.clone(), value));
+			let entry = routes.len() as u32;
+			let addr = prefix.bits().to_bits();
+			let len = prefix.len();
+			if len <= TBL24_BITS {
+				let start = (addr >> (32 - TBL24_BITS)) as usize;
+				let count = 1 << (TBL24_BITS - len);
+				tbl24[start..start + count].fill(entry);
+			} else {
+				let slot = &mut tbl24[(addr >> (32 - TBL24_BITS)) as usize];
+				if 0 == *slot & GROUP_FLAG {
+					// new group inherits shorter route
+					let group = tbl8.len() / GROUP_SIZE;
+					tbl8.resize(tbl8.len() + GROUP_SIZE, *slot);
+					*slot = group as u32 | GROUP_FLAG;
+				}
+				let group = (*slot & !GROUP_FLAG) as usize;
+				let start = group * GROUP_SIZE + (addr as usize % GROUP_SIZE);
+				let count = 1 << (32 - len);
+				tbl8[start..start + count].fill(entry);
+			}
+		}
+		Ipv4LpmTable {
+			routes,
+			tbl24,
+			tbl8,
+		}
+	}
+}
+
+impl<V> FromIterator<(BitLengthString<Ipv4Addr>, V)> for Ipv4LpmTable<V> {
+	fn from_iter<I: IntoIterator<Item = (BitLengthString<Ipv4Addr>, V)>>(iter: I) -> Self {
+		Self::new(iter)
+	}
+}
+
+impl<V: fmt::Debug> fmt::Debug for Ipv4LpmTable<V> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_map().entries(self.routes()).finish()
+	}
+}
@@ -0,0 +1,178 @@
+use alloc::vec::Vec;
+use core::{
+	fmt,
+	hash::Hash,
+};
+
+use crate::{
+	lpm::hash_table::HashTable,
+	BitLengthString,
+	BitString,
+	FixedBitString,
+	PrefixMap,
+};
+
+/// Compiled longest-prefix-match table for any [`FixedBitString`] key
+/// using binary search on prefix lengths (Waldvogel et al.).
+///
+/// For each prefix length occurring in the routes there is a hash
+/// table containing the routes of that length and "markers": when a
+/// binary search step finds an entry in the table it continues with
+/// longer prefix lengths, otherwise with shorter ones.  Markers make
+/// sure the search continues with longer lengths if a longer route
+/// might match, and each entry stores the best matching route so no
+/// backtracking is needed.
+///
+/// A lookup needs `log2(number of distinct prefix lengths) + 1` hash
+/// table lookups at most (i.e. at most 8 for IPv6).
+pub struct LengthSearchTable<W: FixedBitString, V> {
+	routes: Vec<(BitLengthString<W>, V)>,
+	/// sorted distinct prefix lengths
+	lengths: Vec<usize>,
+	/// per prefix length: index of best matching route for routes and
+	/// markers
+	tables: Vec<HashTable<W, Option<usize>>>,
+}
+
+/// Visit all steps of the binary search for length index `target` in
+/// `0..count` that continue with longer prefix lengths.
+fn search_path_markers(count: usize, target: usize, mut f: impl FnMut(usize)) {
+	let (mut lo, mut hi) = (0, count);
+	while lo < hi {
+		let mid = (lo + hi) / 2;
+		if mid == target {
+			return;
+		} else if mid < target {
+			f(mid);
+			lo = mid + 1;
+		} else {
+			hi = mid;
+		}
+	}
+}
+
+impl<W, V> LengthSearchTable<W, V>
+where
+	W: FixedBitString + Clone + Eq + Hash,
+{
+	/// Build table from routes.
+	///
+	/// If a prefix is present multiple times the last value wins.
+	pub fn new<I>(routes: I) -> Self
+	where
+		I: IntoIterator<Item = (BitLengthString<W>, V)>,
+	{
+		Self::from(routes.into_iter().collect::<PrefixMap<_, _>>())
+	}
+
+	/// Find value of the longest route containing `addr`
+	pub fn lookup(&self, addr: &W) -> Option<&V> {
+		Some(&self.routes[self.find(addr)?].1)
+	}
+
+	/// Find the longest route containing `addr`
+	pub fn lookup_route(&self, addr: &W) -> Option<(&BitLengthString<W>, &V)> {
+		let (prefix, value) = &self.routes[self.find(addr)?];
+		Some((prefix, value))
+	}
+
+	fn find(&self, addr: &W) -> Option<usize> {
+		let (mut lo, mut hi) = (0, self.lengths.len());
+		let mut best = None;
+		while lo < hi {
+			let mid = (lo + hi) / 2;
+			let mut key = addr.clone();
+			key.set_false_from(self.lengths[mid]);
+			match self.tables[mid].get(&key) {
+				Some(&entry) => {
+					// entry knows best match; no need to remember
+					// shorter matches
+					best = entry;
+					lo = mid + 1;
+				},
+				None => hi = mid,
+			}
+		}
+		best
+	}
+}
+
+impl<W: FixedBitString, V> LengthSearchTable<W, V> {
+	/// Number of (distinct) routes in the table
+	pub fn len(&self) -> usize {
+		self.routes.len()
+	}
+
+	/// Whether the table has no routes
+	pub fn is_empty(&self) -> bool {
+		self.routes.is_empty()
+	}
+
+	/// Iterate over all routes in
+	/// [`lexicographic_cmp`](crate::BitString::lexicographic_cmp) order
+	pub fn routes(&self) -> impl ExactSizeIterator<Item = (&BitLengthString<W>, &V)> {
+		self.routes.iter().map(|(prefix, value)| (prefix, value))
+	}
+}
+
+impl<W, V> From<PrefixMap<BitLengthString<W>, V>> for LengthSearchTable<W, V>
+where
+	W: FixedBitString + Clone + Eq + Hash,
+{
+	/// Build table from routes in a [`PrefixMap`].
+	fn from(map: PrefixMap<BitLengthString<W>, V>) -> Self {
+		let mut lengths: Vec<usize> = map.keys().map(|prefix| prefix.len()).collect();
+		lengths.sort_unstable();
+		lengths.dedup();
+		let length_index = |len: usize| lengths.binary_search(&len).expect("known length");
+
+		let routes: Vec<_> = map.into_iter().collect();
+		let route_index: PrefixMap<_, _> = routes
+			.iter()
+			.enumerate()
+			.map(|(ndx, (prefix, _))| (prefix.clone(), ndx))
+			.collect();
+
+		// collect routes and markers; markers might be shared
+		let mut entries = PrefixMap::new();
+		for (prefix, _) in &routes {
+			search_path_markers(lengths.len(), length_index(prefix.len()), |mid| {
+				let mut marker = prefix.clone();
+				marker.clip(lengths[mid]);
+				entries.insert(marker, ());
+			});
+			entries.insert(prefix.clone(), ());
+		}
+
+		let mut counts = alloc::vec![0; lengths.len()];
+		for key in entries.keys() {
+			counts[length_index(key.len())] += 1;
+		}
+		let mut tables: Vec<_> = counts.into_iter().map(HashTable::with_capacity).collect();
+		for (key, ()) in entries {
+			let best = route_index.longest_match(&key).map(|(_, &ndx)| ndx);
+			tables[length_index(key.len())].insert(key.bits().clone(), best);
+		}
+
+		LengthSearchTable {
+			routes,
+			lengths,
+			tables,
+		}
+	}
+}
+
+impl<W, V> FromIterator<(BitLengthString<W>, V)> for LengthSearchTable<W, V>
+where
+	W: FixedBitString + Clone + Eq + Hash,
+{
+	fn from_iter<I: IntoIterator<Item = (BitLengthString<W>, V)>>(iter: I) -> Self {
+		Self::new(iter)
+	}
+}
+
+impl<W: FixedBitString + fmt::Debug, V: fmt::Debug> fmt::Debug for LengthSearchTable<W, V> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_map().entries(self.routes()).finish()
+	}
+}
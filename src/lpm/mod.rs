@@ -0,0 +1,26 @@
+//! Immutable tables for fast longest-prefix-match lookups.
+
+#[cfg(feature = "std")]
+pub use self::concurrent::{
+	ConcurrentLpmTable,
+	LpmReader,
+};
+#[cfg(feature = "net")]
+pub use self::ipv4::Ipv4LpmTable;
+pub use self::length_search::LengthSearchTable;
+
+/// Longest-prefix-match table for [`Ipv6Addr`](core::net::Ipv6Addr)
+/// keys.
+#[cfg(feature = "net")]
+pub type Ipv6LpmTable<V> = LengthSearchTable<core::net::Ipv6Addr, V>;
+
+#[cfg(feature = "std")]
+mod concurrent;
+mod hash_table;
+#[cfg(feature = "net")]
+mod ipv4;
+mod length_search;
+
+#[cfg(feature = "net")]
+#[cfg(test)]
+mod tests;
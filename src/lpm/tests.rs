@@ -0,0 +1,183 @@
+use alloc::vec::Vec;
+use core::net::{
+	Ipv4Addr,
+	Ipv6Addr,
+};
+
+use crate::{
+	lpm::{
+		Ipv4LpmTable,
+		Ipv6LpmTable,
+		LengthSearchTable,
+	},
+	test_utils::lcg,
+	BitLengthString,
+	PrefixMap,
+};
+
+#[test]
+fn test_ipv4_simple() {
+	let p = |addr: [u8; 4], len| BitLengthString::new(Ipv4Addr::from(addr), len);
+	let table = Ipv4LpmTable::new([
+		(p([10, 0, 0, 0], 8), 1),
+		(p([10, 1, 2, 0], 24), 2),
+		(p([10, 1, 2, 128], 25), 3),
+		(p([10, 1, 2, 130], 32), 4),
+		(p([10, 1, 2, 130], 32), 5),
+		(p([192, 168, 0, 0], 16), 6),
+	]);
+	assert_eq!(table.len(), 5);
+	assert_eq!(table.lookup(Ipv4Addr::new(10, 2, 0, 0)), Some(&1));
+	assert_eq!(table.lookup(Ipv4Addr::new(10, 1, 2, 3)), Some(&2));
+	assert_eq!(table.lookup(Ipv4Addr::new(10, 1, 2, 129)), Some(&3));
+	assert_eq!(table.lookup(Ipv4Addr::new(10, 1, 2, 130)), Some(&5));
+	assert_eq!(table.lookup(Ipv4Addr::new(11, 0, 0, 0)), None);
+	assert_eq!(
+		table.lookup_route(Ipv4Addr::new(192, 168, 1, 1)),
+		Some((&p([192, 168, 0, 0], 16), &6))
+	);
+}
+
+#[test]
+fn test_ipv4_random() {
+	let mut next = lcg(0x1234_5678);
+	let mut map = PrefixMap::new();
+	for i in 0..2000 {
+		// cluster addresses so routes overlap
+		let addr = Ipv4Addr::from_bits(next() & 0xc0ff_03ff);
+		let len = 8 + next() as usize % 25;
+		map.insert(BitLengthString::new(addr, len), i);
+	}
+	let table = Ipv4LpmTable::from(map.clone());
+	assert_eq!(table.len(), map.len());
+
+	let addrs: Vec<_> = (0..10_000)
+		.map(|_| Ipv4Addr::from_bits(next() & 0xc0ff_03ff))
+		.collect();
+	let mut results = alloc::vec![None; addrs.len()];
+	table.lookup_batch(&addrs, &mut results);
+	for (addr, result) in addrs.iter().zip(results) {
+		let expected = map
+			.longest_match(&BitLengthString::new(*addr, 32))
+			.map(|(_, v)| v);
+		assert_eq!(table.lookup(*addr), expected);
+		assert_eq!(result, expected);
+	}
+}
+
+#[test]
+fn test_length_search_simple() {
+	let p = |addr: &str, len| BitLengthString::new(addr.parse::<Ipv6Addr>().unwrap(), len);
+	let table = Ipv6LpmTable::new([
+		(p("2001:db8::", 32), 1),
+		(p("2001:db8:1::", 48), 2),
+		(p("2001:db8:1:2::", 64), 3),
+		(p("2001:db8:1:2::1", 128), 4),
+		(p("::", 0), 5),
+	]);
+	let lookup = |addr: &str| table.lookup(&addr.parse().unwrap()).copied();
+	assert_eq!(lookup("2001:db8:1:2::1"), Some(4));
+	assert_eq!(lookup("2001:db8:1:2::2"), Some(3));
+	assert_eq!(lookup("2001:db8:1:3::"), Some(2));
+	assert_eq!(lookup("2001:db8:2::"), Some(1));
+	assert_eq!(lookup("2001:db9::"), Some(5));
+	assert_eq!(
+		Ipv6LpmTable::<()>::new([]).lookup(&Ipv6Addr::UNSPECIFIED),
+		None
+	);
+}
+
+#[test]
+fn test_length_search_random() {
+	let mut next = lcg(0x8765_4321);
+	let mut next_addr = || {
+		// cluster addresses so routes overlap
+		let high = (next() & 0xc0ff_03ff) as u128;
+		Ipv6Addr::from_bits(high << 96 | (next() & 0xf) as u128)
+	};
+	let mut map = PrefixMap::new();
+	for i in 0..2000 {
+		let addr = next_addr();
+		let len = [0, 3, 16, 20, 24, 30, 31, 32, 48, 64, 125, 126, 127, 128][i % 14];
+		map.insert(BitLengthString::new(addr, len), i);
+	}
+	let table = LengthSearchTable::from(map.clone());
+	assert_eq!(table.len(), map.len());
+
+	for _ in 0..10_000 {
+		let addr = next_addr();
+		let expected = map.longest_match(&BitLengthString::new(addr, 128));
+		assert_eq!(table.lookup_route(&addr), expected);
+	}
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_concurrent() {
+	use std::sync::atomic::{
+		AtomicBool,
+		Ordering,
+	};
+
+	use crate::lpm::ConcurrentLpmTable;
+
+	const READERS: usize = 8;
+	const BATCHES: u32 = 50;
+
+	// default route and some more specific routes
+	let prefixes: Vec<_> = (0..64u32)
+		.map(|i| BitLengthString::new(Ipv4Addr::from_bits(i << 24), (i as usize % 3) * 8))
+		.collect();
+	let mut table = ConcurrentLpmTable::default();
+	table.update(|routes| {
+		for prefix in &prefixes {
+			routes.insert(prefix.clone(), 0);
+		}
+	});
+	let done = AtomicBool::new(false);
+
+	std::thread::scope(|scope| {
+		for thread in 0..READERS {
+			let mut reader = table.reader();
+			let done = &done;
+			scope.spawn(move || {
+				let mut next = lcg(thread as u32);
+				let mut last_generation = 0;
+				let mut reads = 0u64;
+				while !done.load(Ordering::Relaxed) || reads < 1000 {
+					reads += 1;
+					if next() % 16 == 0 {
+						// each batch updates all routes: must never see a
+						// partially applied batch
+						let generation = reader.read(|routes| {
+							let mut values = routes.values();
+							let first = *values.next().unwrap();
+							assert!(values.all(|&value| value == first));
+							first
+						});
+						assert!(generation >= last_generation);
+						last_generation = generation;
+					} else {
+						let addr = Ipv4Addr::from_bits(next() & 0x3fff_ffff);
+						let generation = reader.lookup(&addr).unwrap();
+						assert!(generation >= last_generation);
+						last_generation = generation;
+					}
+				}
+			});
+		}
+
+		for generation in 1..=BATCHES {
+			table.update(|routes| {
+				for prefix in &prefixes {
+					routes.insert(prefix.clone(), generation);
+				}
+			});
+		}
+		done.store(true, Ordering::Relaxed);
+	});
+
+	let mut reader = table.reader();
+	assert_eq!(reader.lookup(&Ipv4Addr::new(1, 2, 3, 4)), Some(BATCHES));
+	assert_eq!(reader.snapshot(), *table.routes());
+}
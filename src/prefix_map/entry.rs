@@ -0,0 +1,178 @@
+use alloc::boxed::Box;
+
+use crate::{
+	prefix_map::node::Node,
+	BitString,
+};
+
+/// A view into a single entry of a [`PrefixMap`](crate::PrefixMap),
+/// which may either be vacant or occupied.
+///
+/// Created by [`PrefixMap::entry()`](crate::PrefixMap::entry).
+pub enum Entry<'a, K, V> {
+	/// Entry without value
+	Vacant(VacantEntry<'a, K, V>),
+	/// Entry with value
+	Occupied(OccupiedEntry<'a, K, V>),
+}
+
+impl<'a, K: BitString + Clone, V> Entry<'a, K, V> {
+	/// Key of the entry
+	pub fn key(&self) -> &K {
+		match self {
+			Entry::Vacant(entry) => entry.key(),
+			Entry::Occupied(entry) => entry.key(),
+		}
+	}
+
+	/// Insert `default` if vacant; return mutable reference to value
+	pub fn or_insert(self, default: V) -> &'a mut V {
+		self.or_insert_with(|| default)
+	}
+
+	/// Insert result of `default()` if vacant; return mutable reference
+	/// to value
+	pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> &'a mut V {
+		match self {
+			Entry::Vacant(entry) => entry.insert(default()),
+			Entry::Occupied(entry) => entry.into_mut(),
+		}
+	}
+
+	/// Insert `V::default()` if vacant; return mutable reference to
+	/// value
+	pub fn or_default(self) -> &'a mut V
+	where
+		V: Default,
+	{
+		self.or_insert_with(V::default)
+	}
+
+	/// Modify value if occupied
+	pub fn and_modify<F: FnOnce(&mut V)>(mut self, f: F) -> Self {
+		if let Entry::Occupied(entry) = &mut self {
+			f(entry.get_mut());
+		}
+		self
+	}
+}
+
+/// A vacant entry of a [`PrefixMap`](crate::PrefixMap)
+pub struct VacantEntry<'a, K, V> {
+	/// slot to insert the key at
+	slot: &'a mut Option<Box<Node<K, V>>>,
+	len: &'a mut usize,
+	key: K,
+}
+
+impl<'a, K: BitString + Clone, V> VacantEntry<'a, K, V> {
+	pub(crate) fn new(slot: &'a mut Option<Box<Node<K, V>>>, len: &'a mut usize, key: K) -> Self {
+		VacantEntry { slot, len, key }
+	}
+
+	/// Key of the entry
+	pub fn key(&self) -> &K {
+		&self.key
+	}
+
+	/// Take ownership of the key
+	pub fn into_key(self) -> K {
+		self.key
+	}
+
+	/// Insert value; return mutable reference to it
+	pub fn insert(self, value: V) -> &'a mut V {
+		*self.len += 1;
+		Node::<K, V>::find_or_create(self.slot, self.key)
+			.value
+			.insert(value)
+	}
+}
+
+/// An occupied entry of a [`PrefixMap`](crate::PrefixMap)
+pub struct OccupiedEntry<'a, K, V> {
+	/// slot containing the node or its parent
+	parent: &'a mut Option<Box<Node<K, V>>>,
+	/// which child of `parent` contains the node, if any
+	child: Option<bool>,
+	len: &'a mut usize,
+}
+
+impl<'a, K: BitString + Clone, V> OccupiedEntry<'a, K, V> {
+	pub(crate) fn new(
+		parent: &'a mut Option<Box<Node<K, V>>>,
+		child: Option<bool>,
+		len: &'a mut usize,
+	) -> Self {
+		OccupiedEntry { parent, child, len }
+	}
+
+	fn node(&self) -> &Node<K, V> {
+		let parent = self.parent.as_deref().expect("occupied entry");
+		match self.child {
+			None => parent,
+			Some(bit) => parent.children[bit as usize]
+				.as_deref()
+				.expect("occupied entry"),
+		}
+	}
+
+	fn into_node(self) -> &'a mut Node<K, V> {
+		let parent = self.parent.as_deref_mut().expect("occupied entry");
+		match self.child {
+			None => parent,
+			Some(bit) => parent.children[bit as usize]
+				.as_deref_mut()
+				.expect("occupied entry"),
+		}
+	}
+
+	/// Key of the entry
+	pub fn key(&self) -> &K {
+		&self.node().key
+	}
+
+	/// Get value
+	pub fn get(&self) -> &V {
+		self.node().value.as_ref().expect("occupied entry")
+	}
+
+	/// Get mutable value
+	pub fn get_mut(&mut self) -> &mut V {
+		let parent = self.parent.as_deref_mut().expect("occupied entry");
+		let node = match self.child {
+			None => parent,
+			Some(bit) => parent.children[bit as usize]
+				.as_deref_mut()
+				.expect("occupied entry"),
+		};
+		node.value.as_mut().expect("occupied entry")
+	}
+
+	/// Get mutable value with lifetime of the map borrow
+	pub fn into_mut(self) -> &'a mut V {
+		self.into_node().value.as_mut().expect("occupied entry")
+	}
+
+	/// Replace value; return old value
+	pub fn insert(&mut self, value: V) -> V {
+		core::mem::replace(self.get_mut(), value)
+	}
+
+	/// Remove entry from map; return value
+	pub fn remove(self) -> V {
+		self.remove_entry().1
+	}
+
+	/// Remove entry from map; return key and value
+	pub fn remove_entry(self) -> (K, V) {
+		*self.len -= 1;
+		let Some(bit) = self.child else {
+			return Node::<K, V>::take_entry(self.parent);
+		};
+		let parent = self.parent.as_mut().expect("occupied entry");
+		let result = Node::<K, V>::take_entry(&mut parent.children[bit as usize]);
+		Node::<K, V>::compress(self.parent);
+		result
+	}
+}
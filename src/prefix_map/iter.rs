@@ -0,0 +1,320 @@
+use alloc::{
+	boxed::Box,
+	vec::Vec,
+};
+
+use crate::{
+	prefix_map::node::{
+		Link,
+		Node,
+		Owned,
+	},
+	BitString,
+};
+
+/// Pre-order traversal of (sub)tries; yields entries in
+/// [`lexicographic_cmp`](BitString::lexicographic_cmp) order.
+pub(crate) struct Traversal<'a, K, V, L: Link<K, V> = Owned> {
+	/// nodes to visit; the last node is visited next
+	pub(crate) stack: Vec<&'a Node<K, V, L>>,
+}
+
+impl<'a, K, V, L: Link<K, V>> Traversal<'a, K, V, L> {
+	pub(crate) fn new(root: Option<&'a Node<K, V, L>>) -> Self {
+		Traversal {
+			stack: root.into_iter().collect(),
+		}
+	}
+
+	pub(crate) fn next_entry(&mut self) -> Option<(&'a K, &'a V)> {
+		while let Some(node) = self.stack.pop() {
+			// visit `false` branch first: push it last
+			self.stack
+				.extend(node.children.iter().rev().filter_map(|c| c.as_deref()));
+			if let Some(value) = &node.value {
+				return Some((&node.key, value));
+			}
+		}
+		None
+	}
+}
+
+impl<K, V, L: Link<K, V>> Clone for Traversal<'_, K, V, L> {
+	fn clone(&self) -> Self {
+		Traversal {
+			stack: self.stack.clone(),
+		}
+	}
+}
+
+/// Iterator over all entries of a [`PrefixMap`](crate::PrefixMap) or
+/// [`PersistentPrefixMap`](crate::PersistentPrefixMap).
+///
+/// Created by [`PrefixMap::iter()`](crate::PrefixMap::iter) and
+/// [`PersistentPrefixMap::iter()`](crate::PersistentPrefixMap::iter).
+#[derive(Clone)]
+pub struct Iter<'a, K, V, L: Link<K, V> = Owned> {
+	traversal: Traversal<'a, K, V, L>,
+	remaining: usize,
+}
+
+impl<'a, K, V, L: Link<K, V>> Iter<'a, K, V, L> {
+	pub(crate) fn new(root: Option<&'a Node<K, V, L>>, len: usize) -> Self {
+		Iter {
+			traversal: Traversal::new(root),
+			remaining: len,
+		}
+	}
+}
+
+impl<'a, K, V, L: Link<K, V>> Iterator for Iter<'a, K, V, L> {
+	type Item = (&'a K, &'a V);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let entry = self.traversal.next_entry()?;
+		self.remaining -= 1;
+		Some(entry)
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(self.remaining, Some(self.remaining))
+	}
+}
+
+impl<K, V, L: Link<K, V>> ExactSizeIterator for Iter<'_, K, V, L> {}
+
+/// Iterator over all entries of a [`PrefixMap`](crate::PrefixMap) with
+/// mutable values.
+///
+/// Created by [`PrefixMap::iter_mut()`](crate::PrefixMap::iter_mut).
+pub struct IterMut<'a, K, V> {
+	stack: Vec<&'a mut Node<K, V>>,
+	remaining: usize,
+}
+
+impl<'a, K, V> IterMut<'a, K, V> {
+	pub(crate) fn new(root: Option<&'a mut Node<K, V>>, len: usize) -> Self {
+		IterMut {
+			stack: root.into_iter().collect(),
+			remaining: len,
+		}
+	}
+}
+
+impl<'a, K, V> Iterator for IterMut<'a, K, V> {
+	type Item = (&'a K, &'a mut V);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		while let Some(node) = self.stack.pop() {
+			let Node {
+				key,
+				value,
+				children,
+			} = node;
+			// visit `false` branch first: push it last
+			self.stack
+				.extend(children.iter_mut().rev().filter_map(|c| c.as_deref_mut()));
+			if let Some(value) = value {
+				self.remaining -= 1;
+				return Some((&*key, value));
+			}
+		}
+		None
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(self.remaining, Some(self.remaining))
+	}
+}
+
+impl<K, V> ExactSizeIterator for IterMut<'_, K, V> {}
+
+/// Owning iterator over all entries of a
+/// [`PrefixMap`](crate::PrefixMap).
+///
+/// Created by [`PrefixMap::into_iter()`](crate::PrefixMap::into_iter).
+pub struct IntoIter<K, V> {
+	stack: Vec<Box<Node<K, V>>>,
+	remaining: usize,
+}
+
+impl<K, V> IntoIter<K, V> {
+	pub(crate) fn new(root: Option<Box<Node<K, V>>>, len: usize) -> Self {
+		IntoIter {
+			stack: root.into_iter().collect(),
+			remaining: len,
+		}
+	}
+}
+
+impl<K, V> Iterator for IntoIter<K, V> {
+	type Item = (K, V);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		while let Some(node) = self.stack.pop() {
+			let Node {
+				key,
+				value,
+				children: [left, right],
+			} = *node;
+			// visit `false` branch first: push it last
+			self.stack.extend(right);
+			self.stack.extend(left);
+			if let Some(value) = value {
+				self.remaining -= 1;
+				return Some((key, value));
+			}
+		}
+		None
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(self.remaining, Some(self.remaining))
+	}
+}
+
+impl<K, V> ExactSizeIterator for IntoIter<K, V> {}
+
+impl<K, V> Drop for IntoIter<K, V> {
+	fn drop(&mut self) {
+		for node in self.stack.drain(..) {
+			Node::<K, V>::drop_trie(Some(node));
+		}
+	}
+}
+
+/// Iterator over all keys of a [`PrefixMap`](crate::PrefixMap) or
+/// [`PersistentPrefixMap`](crate::PersistentPrefixMap).
+///
+/// Created by [`PrefixMap::keys()`](crate::PrefixMap::keys) and
+/// [`PersistentPrefixMap::keys()`](crate::PersistentPrefixMap::keys).
+#[derive(Clone)]
+pub struct Keys<'a, K, V, L: Link<K, V> = Owned> {
+	inner: Iter<'a, K, V, L>,
+}
+
+impl<'a, K, V, L: Link<K, V>> Keys<'a, K, V, L> {
+	pub(crate) fn new(inner: Iter<'a, K, V, L>) -> Self {
+		Keys { inner }
+	}
+}
+
+impl<'a, K, V, L: Link<K, V>> Iterator for Keys<'a, K, V, L> {
+	type Item = &'a K;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		Some(self.inner.next()?.0)
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.inner.size_hint()
+	}
+}
+
+impl<K, V, L: Link<K, V>> ExactSizeIterator for Keys<'_, K, V, L> {}
+
+/// Iterator over all values of a [`PrefixMap`](crate::PrefixMap) or
+/// [`PersistentPrefixMap`](crate::PersistentPrefixMap).
+///
+/// Created by [`PrefixMap::values()`](crate::PrefixMap::values) and
+/// [`PersistentPrefixMap::values()`](crate::PersistentPrefixMap::values).
+#[derive(Clone)]
+pub struct Values<'a, K, V, L: Link<K, V> = Owned> {
+	inner: Iter<'a, K, V, L>,
+}
+
+impl<'a, K, V, L: Link<K, V>> Values<'a, K, V, L> {
+	pub(crate) fn new(inner: Iter<'a, K, V, L>) -> Self {
+		Values { inner }
+	}
+}
+
+impl<'a, K, V, L: Link<K, V>> Iterator for Values<'a, K, V, L> {
+	type Item = &'a V;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		Some(self.inner.next()?.1)
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.inner.size_hint()
+	}
+}
+
+impl<K, V, L: Link<K, V>> ExactSizeIterator for Values<'_, K, V, L> {}
+
+/// Iterator over all mutable values of a
+/// [`PrefixMap`](crate::PrefixMap).
+///
+/// Created by [`PrefixMap::values_mut()`](crate::PrefixMap::values_mut).
+pub struct ValuesMut<'a, K, V> {
+	inner: IterMut<'a, K, V>,
+}
+
+impl<'a, K, V> ValuesMut<'a, K, V> {
+	pub(crate) fn new(inner: IterMut<'a, K, V>) -> Self {
+		ValuesMut { inner }
+	}
+}
+
+impl<'a, K, V> Iterator for ValuesMut<'a, K, V> {
+	type Item = &'a mut V;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		Some(self.inner.next()?.1)
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.inner.size_hint()
+	}
+}
+
+impl<K, V> ExactSizeIterator for ValuesMut<'_, K, V> {}
+
+/// Iterator over all entries of a [`PrefixMap`](crate::PrefixMap) or
+/// [`PersistentPrefixMap`](crate::PersistentPrefixMap) with keys that
+/// are a prefix of a given key.
+///
+/// Yields shorter keys first.
+///
+/// Created by [`PrefixMap::matches()`](crate::PrefixMap::matches) and
+/// [`PersistentPrefixMap::matches()`](crate::PersistentPrefixMap::matches).
+pub struct Matches<'a, 'k, K, V, L: Link<K, V> = Owned> {
+	next: Option<&'a Node<K, V, L>>,
+	key: &'k K,
+}
+
+impl<'a, 'k, K, V, L: Link<K, V>> Matches<'a, 'k, K, V, L> {
+	pub(crate) fn new(root: Option<&'a Node<K, V, L>>, key: &'k K) -> Self {
+		Matches { next: root, key }
+	}
+}
+
+impl<K, V, L: Link<K, V>> Clone for Matches<'_, '_, K, V, L> {
+	fn clone(&self) -> Self {
+		Matches {
+			next: self.next,
+			key: self.key,
+		}
+	}
+}
+
+impl<'a, K: BitString, V, L: Link<K, V>> Iterator for Matches<'a, '_, K, V, L> {
+	type Item = (&'a K, &'a V);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		while let Some(node) = self.next.take() {
+			let spl = node.key.shared_prefix_len(self.key);
+			if spl < node.key.len() {
+				return None;
+			}
+			if spl < self.key.len() {
+				self.next = node.children[self.key.get(spl) as usize].as_deref();
+			}
+			if let Some(value) = &node.value {
+				return Some((&node.key, value));
+			}
+		}
+		None
+	}
+}
@@ -0,0 +1,318 @@
+use alloc::boxed::Box;
+use core::{
+	cmp::Ordering,
+	fmt,
+	ops::RangeBounds,
+};
+
+use crate::{
+	diff::{
+		diff_by,
+		Diff,
+	},
+	prefix_map::{
+		node::{
+			Location,
+			Node,
+		},
+		Covered,
+		Entry,
+		IntoIter,
+		Iter,
+		IterMut,
+		Keys,
+		Matches,
+		OccupiedEntry,
+		Range,
+		VacantEntry,
+		Values,
+		ValuesMut,
+	},
+	BitString,
+};
+
+/// Map from [`BitString`] keys to values, stored as path compressed
+/// binary trie (also known as PATRICIA or radix trie).
+///
+/// Iteration yields entries in [`lexicographic_cmp`] order of the keys;
+/// i.e. a key is visited before all keys it is a prefix of.
+///
+/// The number of internal nodes is less than the number of entries, so
+/// memory usage is proportional to the number of entries.
+///
+/// [`lexicographic_cmp`]: BitString::lexicographic_cmp
+#[derive(Clone)]
+pub struct PrefixMap<K, V> {
+	pub(crate) root: Option<Box<Node<K, V>>>,
+	len: usize,
+}
+
+impl<K, V> PrefixMap<K, V> {
+	/// Create new empty map
+	pub const fn new() -> Self {
+		PrefixMap { root: None, len: 0 }
+	}
+
+	/// Number of entries in the map
+	pub fn len(&self) -> usize {
+		self.len
+	}
+
+	/// Whether the map is empty
+	pub fn is_empty(&self) -> bool {
+		0 == self.len
+	}
+
+	/// Remove all entries
+	pub fn clear(&mut self) {
+		Node::<K, V>::drop_trie(self.root.take());
+		self.len = 0;
+	}
+
+	/// Iterate over all entries in [`lexicographic_cmp`] order of the
+	/// keys
+	///
+	/// [`lexicographic_cmp`]: BitString::lexicographic_cmp
+	pub fn iter(&self) -> Iter<'_, K, V> {
+		Iter::new(self.root.as_deref(), self.len)
+	}
+
+	/// Iterate over all entries in [`lexicographic_cmp`] order of the
+	/// keys with mutable access to the values
+	///
+	/// [`lexicographic_cmp`]: BitString::lexicographic_cmp
+	pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+		IterMut::new(self.root.as_deref_mut(), self.len)
+	}
+
+	/// Iterate over all keys in [`lexicographic_cmp`] order
+	///
+	/// [`lexicographic_cmp`]: BitString::lexicographic_cmp
+	pub fn keys(&self) -> Keys<'_, K, V> {
+		Keys::new(self.iter())
+	}
+
+	/// Iterate over all values in [`lexicographic_cmp`] order of the
+	/// keys
+	///
+	/// [`lexicographic_cmp`]: BitString::lexicographic_cmp
+	pub fn values(&self) -> Values<'_, K, V> {
+		Values::new(self.iter())
+	}
+
+	/// Iterate mutably over all values in [`lexicographic_cmp`] order
+	/// of the keys
+	///
+	/// [`lexicographic_cmp`]: BitString::lexicographic_cmp
+	pub fn values_mut(&mut self) -> ValuesMut<'_, K, V> {
+		ValuesMut::new(self.iter_mut())
+	}
+}
+
+impl<K: BitString + Clone, V> PrefixMap<K, V> {
+	/// Get value for exactly the given key
+	pub fn get(&self, key: &K) -> Option<&V> {
+		Node::find(self.root.as_deref(), key)?.value.as_ref()
+	}
+
+	/// Get key and value for exactly the given key
+	pub fn get_key_value(&self, key: &K) -> Option<(&K, &V)> {
+		let node = Node::find(self.root.as_deref(), key)?;
+		Some((&node.key, node.value.as_ref()?))
+	}
+
+	/// Get mutable value for exactly the given key
+	pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+		Node::find_mut(self.root.as_deref_mut(), key)?
+			.value
+			.as_mut()
+	}
+
+	/// Whether map contains a value for exactly the given key
+	pub fn contains_key(&self, key: &K) -> bool {
+		self.get(key).is_some()
+	}
+
+	/// Find entry with the longest key that is a prefix of `key`
+	/// (including `key` itself)
+	pub fn longest_match(&self, key: &K) -> Option<(&K, &V)> {
+		self.matches(key).last()
+	}
+
+	/// Find value for the longest key that is a prefix of `key`
+	/// (including `key` itself) with mutable access
+	pub fn longest_match_mut(&mut self, key: &K) -> Option<(&K, &mut V)> {
+		let mut slot = self.root.as_deref_mut();
+		let mut result = None;
+		while let Some(node) = slot {
+			let spl = node.key.shared_prefix_len(key);
+			if spl < node.key.len() {
+				break;
+			}
+			let Node {
+				key: node_key,
+				value,
+				children,
+			} = node;
+			if let Some(value) = value {
+				result = Some((&*node_key, value));
+			}
+			if spl == key.len() {
+				break;
+			}
+			slot = children[key.get(spl) as usize].as_deref_mut();
+		}
+		result
+	}
+
+	/// Iterate over all entries with keys that are a prefix of `key`
+	/// (including `key` itself), starting with the shortest key.
+	///
+	/// See [`covered`](PrefixMap::covered) for entries with keys that
+	/// have `key` as prefix.
+	pub fn matches<'k>(&self, key: &'k K) -> Matches<'_, 'k, K, V> {
+		Matches::new(self.root.as_deref(), key)
+	}
+
+	/// Iterate over all entries with keys that have `key` as prefix
+	/// (including `key` itself) in [`lexicographic_cmp`] order.
+	///
+	/// See [`matches`](PrefixMap::matches) for entries with keys that
+	/// are a prefix of `key`.
+	///
+	/// [`lexicographic_cmp`]: BitString::lexicographic_cmp
+	pub fn covered(&self, key: &K) -> Covered<'_, K, V> {
+		Covered::new(self.root.as_deref(), key)
+	}
+
+	/// Iterate over all entries with keys in `range` in
+	/// [`lexicographic_cmp`] order.
+	///
+	/// The returned iterator can be repositioned with
+	/// [`Range::seek`].
+	///
+	/// [`lexicographic_cmp`]: BitString::lexicographic_cmp
+	pub fn range<R: RangeBounds<K>>(&self, range: R) -> Range<'_, K, V> {
+		Range::new(self.root.as_deref(), range)
+	}
+
+	/// Compute differences to a `new` version of the map in
+	/// [`lexicographic_cmp`] order of the keys in linear time.
+	///
+	/// [`lexicographic_cmp`]: BitString::lexicographic_cmp
+	#[allow(clippy::type_complexity)]
+	pub fn diff<'a>(
+		&'a self,
+		new: &'a Self,
+	) -> Diff<Iter<'a, K, V>, Iter<'a, K, V>, fn(&&'a K, &&'a K) -> Ordering>
+	where
+		V: PartialEq,
+	{
+		diff_by(self.iter(), new.iter(), |a, b| a.lexicographic_cmp(b))
+	}
+
+	/// Insert new value for key; returns old value if there was one.
+	///
+	/// The key isn't updated if it was already present.
+	pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+		let old = Node::<K, V>::find_or_create(&mut self.root, key)
+			.value
+			.replace(value);
+		if old.is_none() {
+			self.len += 1;
+		}
+		old
+	}
+
+	/// Remove value for exactly the given key; returns removed value.
+	pub fn remove(&mut self, key: &K) -> Option<V> {
+		Some(self.remove_entry(key)?.1)
+	}
+
+	/// Remove entry for exactly the given key; returns removed key and
+	/// value.
+	pub fn remove_entry(&mut self, key: &K) -> Option<(K, V)> {
+		let result = Node::<K, V>::remove(&mut self.root, key)?;
+		self.len -= 1;
+		Some(result)
+	}
+
+	/// Get entry for in-place manipulation
+	pub fn entry(&mut self, key: K) -> Entry<'_, K, V> {
+		match Node::locate(&mut self.root, &key) {
+			Location::Occupied { parent, child } => {
+				Entry::Occupied(OccupiedEntry::new(parent, child, &mut self.len))
+			},
+			Location::Vacant(slot) => Entry::Vacant(VacantEntry::new(slot, &mut self.len, key)),
+		}
+	}
+}
+
+impl<K, V> Drop for PrefixMap<K, V> {
+	fn drop(&mut self) {
+		Node::<K, V>::drop_trie(self.root.take());
+	}
+}
+
+impl<K, V> Default for PrefixMap<K, V> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<K: fmt::Debug, V: fmt::Debug> fmt::Debug for PrefixMap<K, V> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_map().entries(self.iter()).finish()
+	}
+}
+
+impl<K: PartialEq, V: PartialEq> PartialEq for PrefixMap<K, V> {
+	fn eq(&self, other: &Self) -> bool {
+		self.len == other.len && self.iter().eq(other.iter())
+	}
+}
+
+impl<K: Eq, V: Eq> Eq for PrefixMap<K, V> {}
+
+impl<K: BitString + Clone, V> FromIterator<(K, V)> for PrefixMap<K, V> {
+	fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+		let mut map = Self::new();
+		map.extend(iter);
+		map
+	}
+}
+
+impl<K: BitString + Clone, V> Extend<(K, V)> for PrefixMap<K, V> {
+	fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+		for (key, value) in iter {
+			self.insert(key, value);
+		}
+	}
+}
+
+impl<'a, K, V> IntoIterator for &'a PrefixMap<K, V> {
+	type IntoIter = Iter<'a, K, V>;
+	type Item = (&'a K, &'a V);
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter()
+	}
+}
+
+impl<'a, K, V> IntoIterator for &'a mut PrefixMap<K, V> {
+	type IntoIter = IterMut<'a, K, V>;
+	type Item = (&'a K, &'a mut V);
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter_mut()
+	}
+}
+
+impl<K, V> IntoIterator for PrefixMap<K, V> {
+	type IntoIter = IntoIter<K, V>;
+	type Item = (K, V);
+
+	fn into_iter(mut self) -> Self::IntoIter {
+		IntoIter::new(self.root.take(), self.len)
+	}
+}
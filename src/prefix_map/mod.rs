@@ -0,0 +1,33 @@
+//! contains extra types related to [`PrefixMap`](crate::PrefixMap).
+
+pub use self::{
+	entry::{
+		Entry,
+		OccupiedEntry,
+		VacantEntry,
+	},
+	iter::{
+		IntoIter,
+		Iter,
+		IterMut,
+		Keys,
+		Matches,
+		Values,
+		ValuesMut,
+	},
+	range::{
+		Covered,
+		Range,
+	},
+};
+
+mod entry;
+mod iter;
+pub(crate) mod map;
+mod node;
+pub mod persistent;
+mod range;
+
+#[cfg(feature = "net")]
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,361 @@
+use alloc::{
+	boxed::Box,
+	sync::Arc,
+	vec::Vec,
+};
+use core::{
+	mem,
+	ops::Deref,
+};
+
+use crate::BitString;
+
+/// How a [`Node`] holds its children.
+///
+/// Implemented by [`Owned`] and [`Shared`]; not nameable outside the
+/// crate.
+pub trait Link<K, V>: Sized {
+	/// Pointer to a child node
+	type Ptr: Deref<Target = Node<K, V, Self>>;
+
+	/// Deep copy of the pointed to node if not shared
+	#[doc(hidden)]
+	fn clone_ptr(ptr: &Self::Ptr) -> Self::Ptr
+	where
+		K: Clone,
+		V: Clone;
+
+	/// Mutable access to node if not shared
+	#[doc(hidden)]
+	fn get_mut(ptr: &mut Self::Ptr) -> Option<&mut Node<K, V, Self>>;
+}
+
+/// [`Link`] that allows creating and modifying nodes
+pub trait LinkMut<K, V>: Link<K, V> {
+	#[doc(hidden)]
+	fn new_ptr(node: Node<K, V, Self>) -> Self::Ptr;
+
+	/// Mutable access to node; shared nodes get copied first
+	#[doc(hidden)]
+	fn make_mut(ptr: &mut Self::Ptr) -> &mut Node<K, V, Self>;
+}
+
+/// Children are owned exclusively by their parent
+#[derive(Clone, Copy, Debug)]
+pub enum Owned {}
+
+/// Children are reference counted and might be shared between tries
+#[derive(Clone, Copy, Debug)]
+pub enum Shared {}
+
+impl<K, V> Link<K, V> for Owned {
+	type Ptr = Box<Node<K, V, Self>>;
+
+	fn clone_ptr(ptr: &Self::Ptr) -> Self::Ptr
+	where
+		K: Clone,
+		V: Clone,
+	{
+		ptr.clone()
+	}
+
+	fn get_mut(ptr: &mut Self::Ptr) -> Option<&mut Node<K, V, Self>> {
+		Some(ptr)
+	}
+}
+
+impl<K, V> LinkMut<K, V> for Owned {
+	fn new_ptr(node: Node<K, V, Self>) -> Self::Ptr {
+		Box::new(node)
+	}
+
+	fn make_mut(ptr: &mut Self::Ptr) -> &mut Node<K, V, Self> {
+		ptr
+	}
+}
+
+impl<K, V> Link<K, V> for Shared {
+	type Ptr = Arc<Node<K, V, Self>>;
+
+	fn clone_ptr(ptr: &Self::Ptr) -> Self::Ptr
+	where
+		K: Clone,
+		V: Clone,
+	{
+		ptr.clone()
+	}
+
+	fn get_mut(ptr: &mut Self::Ptr) -> Option<&mut Node<K, V, Self>> {
+		Arc::get_mut(ptr)
+	}
+}
+
+impl<K: Clone, V: Clone> LinkMut<K, V> for Shared {
+	fn new_ptr(node: Node<K, V, Self>) -> Self::Ptr {
+		Arc::new(node)
+	}
+
+	fn make_mut(ptr: &mut Self::Ptr) -> &mut Node<K, V, Self> {
+		Arc::make_mut(ptr)
+	}
+}
+
+/// Node in a path compressed binary trie.
+///
+/// Nodes without value always have two children (apart from a
+/// temporary state while inserting a new value).
+pub struct Node<K, V, L: Link<K, V> = Owned> {
+	pub(crate) key: K,
+	pub(crate) value: Option<V>,
+	pub(crate) children: [Option<L::Ptr>; 2],
+}
+
+impl<K: Clone, V: Clone, L: Link<K, V>> Clone for Node<K, V, L> {
+	fn clone(&self) -> Self {
+		let [left, right] = &self.children;
+		Node {
+			key: self.key.clone(),
+			value: self.value.clone(),
+			children: [
+				left.as_ref().map(L::clone_ptr),
+				right.as_ref().map(L::clone_ptr),
+			],
+		}
+	}
+}
+
+impl<K, V, L: Link<K, V>> Node<K, V, L> {
+	fn new(key: K, value: Option<V>) -> Self {
+		Node {
+			key,
+			value,
+			children: [None, None],
+		}
+	}
+
+	/// Drop a (sub)trie without recursion; the depth of a trie is only
+	/// bounded by the length of the keys.
+	///
+	/// Shared nodes are only released, not traversed.
+	pub(crate) fn drop_trie(root: Option<L::Ptr>) {
+		let mut stack: Vec<L::Ptr> = root.into_iter().collect();
+		while let Some(mut ptr) = stack.pop() {
+			if let Some(node) = L::get_mut(&mut ptr) {
+				stack.extend(node.children.iter_mut().filter_map(Option::take));
+			}
+		}
+	}
+}
+
+impl<K: BitString, V, L: Link<K, V>> Node<K, V, L> {
+	/// Find node with exactly the given key (might not have a value)
+	pub(crate) fn find<'a>(mut slot: Option<&'a Self>, key: &K) -> Option<&'a Self> {
+		while let Some(node) = slot {
+			let spl = node.key.shared_prefix_len(key);
+			if spl < node.key.len() {
+				return None;
+			}
+			if spl == key.len() {
+				return Some(node);
+			}
+			slot = node.children[key.get(spl) as usize].as_deref();
+		}
+		None
+	}
+}
+
+/// Position of a key in a trie; see [`Node::locate`]
+pub(crate) enum Location<'a, K, V> {
+	/// Node with a value for the key: the node in the `parent` slot
+	/// itself (`child == None`) or the given child of it
+	Occupied {
+		parent: &'a mut Option<Box<Node<K, V>>>,
+		child: Option<bool>,
+	},
+	/// Slot to pass to [`Node::find_or_create`] to insert the key
+	Vacant(&'a mut Option<Box<Node<K, V>>>),
+}
+
+/// What to do with a node while locating a key
+enum Step {
+	Occupied,
+	Vacant,
+	Descend(bool),
+}
+
+impl<K: BitString, V> Node<K, V> {
+	fn step(slot: &Option<Box<Self>>, key: &K) -> Step {
+		let Some(node) = slot else {
+			return Step::Vacant;
+		};
+		let spl = node.key.shared_prefix_len(key);
+		if spl < node.key.len() {
+			Step::Vacant
+		} else if spl == key.len() {
+			if node.value.is_some() {
+				Step::Occupied
+			} else {
+				Step::Vacant
+			}
+		} else {
+			Step::Descend(key.get(spl))
+		}
+	}
+
+	/// Locate the node for `key` with a single walk; an occupied
+	/// location keeps the parent slot to restore path compression when
+	/// removing the value.
+	pub(crate) fn locate<'a>(root: &'a mut Option<Box<Self>>, key: &K) -> Location<'a, K, V> {
+		let mut bit = match Self::step(root, key) {
+			Step::Occupied => {
+				return Location::Occupied {
+					parent: root,
+					child: None,
+				};
+			},
+			Step::Vacant => return Location::Vacant(root),
+			Step::Descend(bit) => bit,
+		};
+		let mut parent = root;
+		loop {
+			let node = parent.as_ref().expect("node to descend from");
+			let next = match Self::step(&node.children[bit as usize], key) {
+				Step::Occupied => {
+					return Location::Occupied {
+						parent,
+						child: Some(bit),
+					};
+				},
+				Step::Vacant => None,
+				Step::Descend(next) => Some(next),
+			};
+			let child = &mut parent.as_mut().expect("node to descend from").children[bit as usize];
+			match next {
+				None => return Location::Vacant(child),
+				Some(next) => {
+					parent = child;
+					bit = next;
+				},
+			}
+		}
+	}
+
+	/// Find node with exactly the given key (might not have a value)
+	pub(crate) fn find_mut<'a>(mut slot: Option<&'a mut Self>, key: &K) -> Option<&'a mut Self> {
+		while let Some(node) = slot {
+			let spl = node.key.shared_prefix_len(key);
+			if spl < node.key.len() {
+				return None;
+			}
+			if spl == key.len() {
+				return Some(node);
+			}
+			slot = node.children[key.get(spl) as usize].as_deref_mut();
+		}
+		None
+	}
+}
+
+impl<K: BitString + Clone, V, L: LinkMut<K, V>> Node<K, V, L> {
+	/// Find or create node with exactly the given key; copies all
+	/// shared nodes on the path.
+	///
+	/// A created node won't have a value; the caller must set it.
+	pub(crate) fn find_or_create(mut slot: &mut Option<L::Ptr>, key: K) -> &mut Self {
+		loop {
+			let node = match slot {
+				None => return L::make_mut(slot.insert(L::new_ptr(Node::new(key, None)))),
+				Some(node) => L::make_mut(node),
+			};
+			let spl = node.key.shared_prefix_len(&key);
+			if spl < node.key.len() {
+				// need to split: `key` isn't a prefix of `node.key`
+				let old_bit = node.key.get(spl);
+				let key_is_parent = spl == key.len();
+				let parent = if key_is_parent {
+					// but `node.key` extends `key`
+					Node::new(key, None)
+				} else {
+					let mut prefix = key.clone();
+					prefix.clip(spl);
+					let mut parent = Node::new(prefix, None);
+					parent.children[!old_bit as usize] = Some(L::new_ptr(Node::new(key, None)));
+					parent
+				};
+				let old = mem::replace(node, parent);
+				node.children[old_bit as usize] = Some(L::new_ptr(old));
+				if key_is_parent {
+					return node;
+				}
+				let leaf = node.children[!old_bit as usize].as_mut();
+				return L::make_mut(leaf.expect("new leaf was just inserted"));
+			}
+			if spl == key.len() {
+				return node;
+			}
+			slot = &mut node.children[key.get(spl) as usize];
+		}
+	}
+
+	/// Remove value for the given key and restore path compression.
+	///
+	/// Only copies the shared nodes on the path if the key was found.
+	pub(crate) fn remove(root: &mut Option<L::Ptr>, key: &K) -> Option<(K, V)> {
+		let depth = Self::value_depth(root.as_deref(), key)?;
+		if depth == 0 {
+			return Some(Self::take_entry(root));
+		}
+		// walk to the parent of the node with the value
+		let mut slot = root;
+		for _ in 1..depth {
+			let node = L::make_mut(slot.as_mut().expect("node on path"));
+			slot = &mut node.children[key.get(node.key.len()) as usize];
+		}
+		let parent = L::make_mut(slot.as_mut().expect("parent node"));
+		let bit = key.get(parent.key.len());
+		let result = Self::take_entry(&mut parent.children[bit as usize]);
+		Self::compress(slot);
+		Some(result)
+	}
+
+	/// Number of edges from the root to the node with a value for
+	/// exactly the given key
+	fn value_depth(mut slot: Option<&Self>, key: &K) -> Option<usize> {
+		let mut depth = 0;
+		while let Some(node) = slot {
+			let spl = node.key.shared_prefix_len(key);
+			if spl < node.key.len() {
+				return None;
+			}
+			if spl == key.len() {
+				return node.value.is_some().then_some(depth);
+			}
+			slot = node.children[key.get(spl) as usize].as_deref();
+			depth += 1;
+		}
+		None
+	}
+
+	/// Take key and value of the node in `slot` and restore path
+	/// compression of the slot; the node must have a value.
+	pub(crate) fn take_entry(slot: &mut Option<L::Ptr>) -> (K, V) {
+		let node = L::make_mut(slot.as_mut().expect("node with value"));
+		let value = node.value.take().expect("node with value");
+		let key = node.key.clone();
+		Self::compress(slot);
+		(key, value)
+	}
+
+	/// Remove node if it has no value and less than two children
+	pub(crate) fn compress(slot: &mut Option<L::Ptr>) {
+		let Some(node) = slot else {
+			return;
+		};
+		if node.value.is_some() || node.children.iter().all(Option::is_some) {
+			return;
+		}
+		let [left, right] = &mut L::make_mut(node).children;
+		let child = left.take().or_else(|| right.take());
+		*slot = child;
+	}
+}
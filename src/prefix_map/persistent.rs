@@ -0,0 +1,368 @@
+//! contains [`PersistentPrefixMap`] and related types.
+
+use alloc::{
+	sync::Arc,
+	vec::Vec,
+};
+use core::{
+	cmp::Ordering,
+	fmt,
+	ops::RangeBounds,
+};
+
+use crate::{
+	diff::DiffEvent,
+	prefix_map::node::{
+		Node,
+		Shared,
+	},
+	BitString,
+};
+
+/// Iterator over all entries of a [`PersistentPrefixMap`].
+///
+/// Created by [`PersistentPrefixMap::iter()`].
+pub type Iter<'a, K, V> = super::Iter<'a, K, V, Shared>;
+
+/// Iterator over all keys of a [`PersistentPrefixMap`].
+///
+/// Created by [`PersistentPrefixMap::keys()`].
+pub type Keys<'a, K, V> = super::Keys<'a, K, V, Shared>;
+
+/// Iterator over all values of a [`PersistentPrefixMap`].
+///
+/// Created by [`PersistentPrefixMap::values()`].
+pub type Values<'a, K, V> = super::Values<'a, K, V, Shared>;
+
+/// Iterator over all entries of a [`PersistentPrefixMap`] with keys
+/// that are a prefix of a given key.
+///
+/// Created by [`PersistentPrefixMap::matches()`].
+pub type Matches<'a, 'k, K, V> = super::Matches<'a, 'k, K, V, Shared>;
+
+/// Iterator over all entries of a [`PersistentPrefixMap`] with keys
+/// that have a given key as prefix.
+///
+/// Created by [`PersistentPrefixMap::covered()`].
+pub type Covered<'a, K, V> = super::Covered<'a, K, V, Shared>;
+
+/// Iterator over entries of a [`PersistentPrefixMap`] with keys in a
+/// range.
+///
+/// Created by [`PersistentPrefixMap::range()`].
+pub type Range<'a, K, V> = super::Range<'a, K, V, Shared>;
+
+/// Persistent map from [`BitString`] keys to values, stored as path
+/// compressed binary trie with reference counted nodes.
+///
+/// Cloning a map is `O(1)`; clones share all nodes.  Modifications
+/// only copy the shared nodes on the path to the modified entry, so
+/// building a new version from a snapshot costs `O(changes)`.
+///
+/// Iteration yields entries in [`lexicographic_cmp`] order of the keys.
+///
+/// [`lexicographic_cmp`]: BitString::lexicographic_cmp
+pub struct PersistentPrefixMap<K, V> {
+	root: Option<Arc<Node<K, V, Shared>>>,
+	len: usize,
+}
+
+impl<K, V> PersistentPrefixMap<K, V> {
+	/// Create new empty map
+	pub const fn new() -> Self {
+		PersistentPrefixMap { root: None, len: 0 }
+	}
+
+	/// Number of entries in the map
+	pub fn len(&self) -> usize {
+		self.len
+	}
+
+	/// Whether the map is empty
+	pub fn is_empty(&self) -> bool {
+		0 == self.len
+	}
+
+	/// Whether both maps share the same root (and therefore are equal)
+	pub fn ptr_eq(&self, other: &Self) -> bool {
+		match (&self.root, &other.root) {
+			(Some(a), Some(b)) => Arc::ptr_eq(a, b),
+			(None, None) => true,
+			_ => false,
+		}
+	}
+
+	/// Iterate over all entries in [`lexicographic_cmp`] order of the
+	/// keys
+	///
+	/// [`lexicographic_cmp`]: BitString::lexicographic_cmp
+	pub fn iter(&self) -> Iter<'_, K, V> {
+		Iter::new(self.root.as_deref(), self.len)
+	}
+
+	/// Iterate over all keys in [`lexicographic_cmp`] order
+	///
+	/// [`lexicographic_cmp`]: BitString::lexicographic_cmp
+	pub fn keys(&self) -> Keys<'_, K, V> {
+		Keys::new(self.iter())
+	}
+
+	/// Iterate over all values in [`lexicographic_cmp`] order of the
+	/// keys
+	///
+	/// [`lexicographic_cmp`]: BitString::lexicographic_cmp
+	pub fn values(&self) -> Values<'_, K, V> {
+		Values::new(self.iter())
+	}
+}
+
+impl<K: BitString, V> PersistentPrefixMap<K, V> {
+	/// Get value for exactly the given key
+	pub fn get(&self, key: &K) -> Option<&V> {
+		Node::find(self.root.as_deref(), key)?.value.as_ref()
+	}
+
+	/// Get key and value for exactly the given key
+	pub fn get_key_value(&self, key: &K) -> Option<(&K, &V)> {
+		let node = Node::find(self.root.as_deref(), key)?;
+		Some((&node.key, node.value.as_ref()?))
+	}
+
+	/// Whether map contains a value for exactly the given key
+	pub fn contains_key(&self, key: &K) -> bool {
+		self.get(key).is_some()
+	}
+
+	/// Find entry with the longest key that is a prefix of `key`
+	/// (including `key` itself)
+	pub fn longest_match(&self, key: &K) -> Option<(&K, &V)> {
+		self.matches(key).last()
+	}
+
+	/// Iterate over all entries with keys that are a prefix of `key`
+	/// (including `key` itself), starting with the shortest key.
+	pub fn matches<'k>(&self, key: &'k K) -> Matches<'_, 'k, K, V> {
+		Matches::new(self.root.as_deref(), key)
+	}
+
+	/// Iterate over all entries with keys that have `key` as prefix
+	/// (including `key` itself) in [`lexicographic_cmp`] order.
+	///
+	/// [`lexicographic_cmp`]: BitString::lexicographic_cmp
+	pub fn covered(&self, key: &K) -> Covered<'_, K, V> {
+		Covered::new(self.root.as_deref(), key)
+	}
+
+	/// Iterate over all entries with keys in `range` in
+	/// [`lexicographic_cmp`] order.
+	///
+	/// The returned iterator can be repositioned with
+	/// [`Range::seek`](super::Range::seek).
+	///
+	/// [`lexicographic_cmp`]: BitString::lexicographic_cmp
+	pub fn range<R: RangeBounds<K>>(&self, range: R) -> Range<'_, K, V>
+	where
+		K: Clone,
+	{
+		Range::new(self.root.as_deref(), range)
+	}
+
+	/// Compute differences to a `new` version of the map in
+	/// [`lexicographic_cmp`] order of the keys.
+	///
+	/// Subtries shared by both versions are skipped, so the time
+	/// depends on the number of nodes copied by the modifications
+	/// between the versions, not on the size of the maps.
+	///
+	/// [`lexicographic_cmp`]: BitString::lexicographic_cmp
+	pub fn diff<'a>(&'a self, new: &'a Self) -> Diff<'a, K, V>
+	where
+		V: PartialEq,
+	{
+		Diff {
+			old: self.root.iter().map(Pending::Node).collect(),
+			new: new.root.iter().map(Pending::Node).collect(),
+		}
+	}
+}
+
+impl<K: BitString + Clone, V: Clone> PersistentPrefixMap<K, V> {
+	/// Insert new value for key in this version; returns old value if
+	/// there was one.
+	///
+	/// The key isn't updated if it was already present.
+	pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+		let old = Node::<K, V, Shared>::find_or_create(&mut self.root, key)
+			.value
+			.replace(value);
+		if old.is_none() {
+			self.len += 1;
+		}
+		old
+	}
+
+	/// Remove value for exactly the given key from this version;
+	/// returns removed value.
+	pub fn remove(&mut self, key: &K) -> Option<V> {
+		let (_, value) = Node::<K, V, Shared>::remove(&mut self.root, key)?;
+		self.len -= 1;
+		Some(value)
+	}
+
+	/// Create new version with value for key inserted
+	#[must_use]
+	pub fn update(&self, key: K, value: V) -> Self {
+		let mut result = self.clone();
+		result.insert(key, value);
+		result
+	}
+
+	/// Create new version without value for the given key
+	#[must_use]
+	pub fn without(&self, key: &K) -> Self {
+		let mut result = self.clone();
+		result.remove(key);
+		result
+	}
+}
+
+/// Subtrie or entry still to compare in [`Diff`]
+enum Pending<'a, K, V> {
+	Node(&'a Arc<Node<K, V, Shared>>),
+	Entry(&'a K, &'a V),
+}
+
+impl<'a, K, V> Pending<'a, K, V> {
+	/// Smallest key of the subtrie or key of the entry
+	fn key(&self) -> &'a K {
+		match self {
+			Pending::Node(node) => &node.key,
+			Pending::Entry(key, _) => key,
+		}
+	}
+}
+
+/// Differences between two versions of a [`PersistentPrefixMap`].
+///
+/// Created by [`PersistentPrefixMap::diff()`].
+pub struct Diff<'a, K, V> {
+	/// pre-order traversals; the last item is visited next
+	old: Vec<Pending<'a, K, V>>,
+	new: Vec<Pending<'a, K, V>>,
+}
+
+/// Replace node with its value followed by its children
+fn expand<'a, K, V>(stack: &mut Vec<Pending<'a, K, V>>, node: &'a Node<K, V, Shared>) {
+	// visit `false` branch first: push it last
+	stack.extend(node.children.iter().rev().flatten().map(Pending::Node));
+	if let Some(value) = &node.value {
+		stack.push(Pending::Entry(&node.key, value));
+	}
+}
+
+impl<'a, K: BitString, V: PartialEq> Iterator for Diff<'a, K, V> {
+	type Item = DiffEvent<&'a K, &'a V>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		loop {
+			let order = match (self.old.last(), self.new.last()) {
+				(None, None) => return None,
+				(Some(_), None) => Ordering::Less,
+				(None, Some(_)) => Ordering::Greater,
+				(Some(Pending::Node(old)), Some(Pending::Node(new))) if Arc::ptr_eq(old, new) => {
+					// shared subtrie: no differences
+					self.old.pop();
+					self.new.pop();
+					continue;
+				},
+				(Some(old), Some(new)) => old.key().lexicographic_cmp(new.key()),
+			};
+			match order {
+				Ordering::Less => match self.old.pop()? {
+					Pending::Node(node) => expand(&mut self.old, node),
+					Pending::Entry(key, value) => return Some(DiffEvent::Removed(key, value)),
+				},
+				Ordering::Greater => match self.new.pop()? {
+					Pending::Node(node) => expand(&mut self.new, node),
+					Pending::Entry(key, value) => return Some(DiffEvent::Added(key, value)),
+				},
+				// same key: expand nodes until both sides have an entry
+				Ordering::Equal => match (self.old.pop()?, self.new.pop()?) {
+					(Pending::Entry(key, old), Pending::Entry(_, new)) => {
+						if old != new {
+							return Some(DiffEvent::Changed(key, old, new));
+						}
+					},
+					(old, new) => {
+						for (stack, item) in [(&mut self.old, old), (&mut self.new, new)] {
+							match item {
+								Pending::Node(node) => expand(stack, node),
+								entry => stack.push(entry),
+							}
+						}
+					},
+				},
+			}
+		}
+	}
+}
+
+impl<K, V> Clone for PersistentPrefixMap<K, V> {
+	fn clone(&self) -> Self {
+		PersistentPrefixMap {
+			root: self.root.clone(),
+			len: self.len,
+		}
+	}
+}
+
+impl<K, V> Drop for PersistentPrefixMap<K, V> {
+	fn drop(&mut self) {
+		Node::<K, V, Shared>::drop_trie(self.root.take());
+	}
+}
+
+impl<K, V> Default for PersistentPrefixMap<K, V> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<K: fmt::Debug, V: fmt::Debug> fmt::Debug for PersistentPrefixMap<K, V> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_map().entries(self.iter()).finish()
+	}
+}
+
+impl<K: PartialEq, V: PartialEq> PartialEq for PersistentPrefixMap<K, V> {
+	fn eq(&self, other: &Self) -> bool {
+		self.ptr_eq(other) || (self.len == other.len && self.iter().eq(other.iter()))
+	}
+}
+
+impl<K: Eq, V: Eq> Eq for PersistentPrefixMap<K, V> {}
+
+impl<K: BitString + Clone, V: Clone> FromIterator<(K, V)> for PersistentPrefixMap<K, V> {
+	fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+		let mut map = Self::new();
+		map.extend(iter);
+		map
+	}
+}
+
+impl<K: BitString + Clone, V: Clone> Extend<(K, V)> for PersistentPrefixMap<K, V> {
+	fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+		for (key, value) in iter {
+			self.insert(key, value);
+		}
+	}
+}
+
+impl<'a, K, V> IntoIterator for &'a PersistentPrefixMap<K, V> {
+	type IntoIter = Iter<'a, K, V>;
+	type Item = (&'a K, &'a V);
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter()
+	}
+}
@@ -0,0 +1,162 @@
+use core::ops::{
+	Bound,
+	RangeBounds,
+};
+
+use crate::{
+	prefix_map::{
+		iter::Traversal,
+		node::{
+			Link,
+			Node,
+			Owned,
+		},
+	},
+	BitString,
+};
+
+/// Iterator over all entries of a [`PrefixMap`](crate::PrefixMap) or
+/// [`PersistentPrefixMap`](crate::PersistentPrefixMap) with keys that
+/// have a given key as prefix (including the key itself).
+///
+/// Created by [`PrefixMap::covered()`](crate::PrefixMap::covered) and
+/// [`PersistentPrefixMap::covered()`](crate::PersistentPrefixMap::covered).
+#[derive(Clone)]
+pub struct Covered<'a, K, V, L: Link<K, V> = Owned> {
+	traversal: Traversal<'a, K, V, L>,
+}
+
+impl<'a, K: BitString, V, L: Link<K, V>> Covered<'a, K, V, L> {
+	pub(crate) fn new(mut slot: Option<&'a Node<K, V, L>>, key: &K) -> Self {
+		// find root of subtrie
+		while let Some(node) = slot {
+			let spl = node.key.shared_prefix_len(key);
+			if spl == key.len() {
+				break;
+			}
+			slot = if spl == node.key.len() {
+				node.children[key.get(spl) as usize].as_deref()
+			} else {
+				None
+			};
+		}
+		Covered {
+			traversal: Traversal::new(slot),
+		}
+	}
+}
+
+impl<'a, K, V, L: Link<K, V>> Iterator for Covered<'a, K, V, L> {
+	type Item = (&'a K, &'a V);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		self.traversal.next_entry()
+	}
+}
+
+/// Iterator over entries of a [`PrefixMap`](crate::PrefixMap) or
+/// [`PersistentPrefixMap`](crate::PersistentPrefixMap) with keys in a
+/// range (in [`lexicographic_cmp`](crate::BitString::lexicographic_cmp)
+/// order).
+///
+/// Can be repositioned with [`seek`](Range::seek).
+///
+/// Created by [`PrefixMap::range()`](crate::PrefixMap::range) and
+/// [`PersistentPrefixMap::range()`](crate::PersistentPrefixMap::range).
+pub struct Range<'a, K, V, L: Link<K, V> = Owned> {
+	root: Option<&'a Node<K, V, L>>,
+	traversal: Traversal<'a, K, V, L>,
+	start: Bound<K>,
+	end: Bound<K>,
+}
+
+impl<'a, K: BitString + Clone, V, L: Link<K, V>> Range<'a, K, V, L> {
+	pub(crate) fn new<R: RangeBounds<K>>(root: Option<&'a Node<K, V, L>>, range: R) -> Self {
+		let mut result = Range {
+			root,
+			traversal: Traversal::new(root),
+			start: range.start_bound().cloned(),
+			end: range.end_bound().cloned(),
+		};
+		if let Bound::Included(start) | Bound::Excluded(start) = &result.start {
+			Self::position(&mut result.traversal, root, start);
+		}
+		result
+	}
+
+	/// Setup traversal to continue with first entry with key not less
+	/// than `target`
+	fn position(
+		traversal: &mut Traversal<'a, K, V, L>,
+		mut slot: Option<&'a Node<K, V, L>>,
+		target: &K,
+	) {
+		traversal.stack.clear();
+		while let Some(node) = slot {
+			let spl = node.key.shared_prefix_len(target);
+			if spl == target.len() {
+				// target is a prefix of node.key: all entries in subtrie
+				// are not less than target
+				traversal.stack.push(node);
+				return;
+			}
+			if spl < node.key.len() {
+				// node.key and target differ in bit `spl`
+				if node.key.get(spl) {
+					traversal.stack.push(node);
+				}
+				return;
+			}
+			// node.key is a proper prefix of target (and less than it)
+			let [left, right] = &node.children;
+			if target.get(spl) {
+				slot = right.as_deref();
+			} else {
+				traversal.stack.extend(right.as_deref());
+				slot = left.as_deref();
+			}
+		}
+	}
+
+	/// Reposition iterator: continue with the first entry with a key not
+	/// less than `key` (but still in the range this iterator was created
+	/// with).
+	///
+	/// Seeking backwards is allowed too.
+	pub fn seek(&mut self, key: &K) {
+		let start = match &self.start {
+			Bound::Included(start) | Bound::Excluded(start)
+				if start.lexicographic_cmp(key).is_gt() =>
+			{
+				start
+			},
+			_ => key,
+		};
+		Self::position(&mut self.traversal, self.root, start);
+	}
+}
+
+impl<'a, K: BitString, V, L: Link<K, V>> Iterator for Range<'a, K, V, L> {
+	type Item = (&'a K, &'a V);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		loop {
+			let (key, value) = self.traversal.next_entry()?;
+			if let Bound::Excluded(start) = &self.start {
+				if key.lexicographic_cmp(start).is_le() {
+					continue;
+				}
+			}
+			let after_end = match &self.end {
+				Bound::Included(end) => key.lexicographic_cmp(end).is_gt(),
+				Bound::Excluded(end) => key.lexicographic_cmp(end).is_ge(),
+				Bound::Unbounded => false,
+			};
+			if after_end {
+				self.traversal.stack.clear();
+				return None;
+			}
+			return Some((key, value));
+		}
+	}
+}
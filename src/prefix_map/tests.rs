@@ -0,0 +1,309 @@
+use alloc::vec::Vec;
+use core::{
+	net::Ipv4Addr,
+	ops::Bound,
+};
+
+use crate::{
+	diff::DiffEvent,
+	prefix_map::Entry,
+	test_utils::lcg,
+	BitLengthString,
+	BitString,
+	PersistentPrefixMap,
+	PrefixMap,
+};
+
+type Prefix = BitLengthString<Ipv4Addr>;
+
+fn p(addr: [u8; 4], len: usize) -> Prefix {
+	Prefix::new(Ipv4Addr::from(addr), len)
+}
+
+fn sample() -> PrefixMap<Prefix, u32> {
+	[
+		(p([10, 0, 0, 0], 8), 1),
+		(p([10, 1, 0, 0], 16), 2),
+		(p([10, 1, 2, 0], 24), 3),
+		(p([10, 128, 0, 0], 9), 4),
+		(p([0, 0, 0, 0], 0), 5),
+		(p([192, 168, 0, 0], 16), 6),
+	]
+	.into_iter()
+	.collect()
+}
+
+#[test]
+fn test_get_insert_remove() {
+	let mut map = sample();
+	assert_eq!(map.len(), 6);
+	assert_eq!(map.get(&p([10, 1, 0, 0], 16)), Some(&2));
+	assert_eq!(map.get(&p([10, 1, 0, 0], 17)), None);
+	assert_eq!(map.get(&p([10, 0, 0, 0], 7)), None);
+	assert_eq!(map.insert(p([10, 1, 0, 0], 16), 20), Some(2));
+	assert_eq!(map.len(), 6);
+	assert_eq!(map.remove(&p([10, 0, 0, 0], 8)), Some(1));
+	assert_eq!(map.remove(&p([10, 0, 0, 0], 8)), None);
+	assert_eq!(map.len(), 5);
+	assert_eq!(map.get(&p([10, 1, 2, 0], 24)), Some(&3));
+	assert_eq!(map.get(&p([10, 128, 0, 0], 9)), Some(&4));
+	for key in sample().keys() {
+		map.remove(key);
+	}
+	assert!(map.is_empty());
+	assert!(map.root.is_none());
+}
+
+#[test]
+fn test_longest_match() {
+	let map = sample();
+	assert_eq!(
+		map.longest_match(&p([10, 1, 2, 3], 32)),
+		Some((&p([10, 1, 2, 0], 24), &3))
+	);
+	assert_eq!(
+		map.longest_match(&p([10, 1, 3, 3], 32)),
+		Some((&p([10, 1, 0, 0], 16), &2))
+	);
+	assert_eq!(
+		map.longest_match(&p([10, 2, 3, 3], 32)),
+		Some((&p([10, 0, 0, 0], 8), &1))
+	);
+	assert_eq!(
+		map.longest_match(&p([11, 0, 0, 0], 32)),
+		Some((&p([0, 0, 0, 0], 0), &5))
+	);
+	let matches: Vec<_> = map
+		.matches(&p([10, 1, 2, 3], 32))
+		.map(|(_, &v)| v)
+		.collect();
+	assert_eq!(matches, [5, 1, 2, 3]);
+	let matches: Vec<_> = map
+		.matches(&p([10, 1, 0, 0], 16))
+		.map(|(_, &v)| v)
+		.collect();
+	assert_eq!(matches, [5, 1, 2]);
+}
+
+#[test]
+fn test_entry() {
+	let mut map = sample();
+	*map.entry(p([10, 1, 0, 0], 16)).or_insert(0) += 10;
+	*map.entry(p([10, 1, 0, 0], 15)).or_insert(0) += 10;
+	assert_eq!(map.get(&p([10, 1, 0, 0], 16)), Some(&12));
+	assert_eq!(map.get(&p([10, 0, 0, 0], 15)), Some(&10));
+	match map.entry(p([10, 1, 2, 0], 24)) {
+		Entry::Occupied(entry) => assert_eq!(entry.remove(), 3),
+		Entry::Vacant(_) => panic!("expected occupied entry"),
+	}
+	assert_eq!(map.len(), 6);
+	assert!(!map.contains_key(&p([10, 1, 2, 0], 24)));
+}
+
+fn random_prefix(next: &mut impl FnMut() -> u32) -> Prefix {
+	Prefix::new(
+		Ipv4Addr::from_bits(next() & 0xf0f0_0000),
+		(next() % 17) as usize,
+	)
+}
+
+#[test]
+fn test_iter_order() {
+	let mut next = lcg(0x1234_5678);
+	let mut map = PrefixMap::new();
+	let mut reference = Vec::new();
+	for i in 0..1000 {
+		let key = random_prefix(&mut next);
+		if map.insert(key.clone(), i).is_none() {
+			reference.push((key, i));
+		} else {
+			reference.iter_mut().find(|(k, _)| *k == key).unwrap().1 = i;
+		}
+		if next() % 4 == 0 {
+			let (key, value) = reference.swap_remove(next() as usize % reference.len());
+			assert_eq!(map.remove(&key), Some(value));
+		}
+	}
+	reference.sort_by(|a, b| a.0.lexicographic_cmp(&b.0));
+	assert_eq!(map.len(), reference.len());
+	let entries: Vec<_> = map.iter().map(|(k, &v)| (k.clone(), v)).collect();
+	assert_eq!(entries, reference);
+	let entries: Vec<_> = map.into_iter().collect();
+	assert_eq!(entries, reference);
+}
+
+#[test]
+fn test_covered() {
+	let map = sample();
+	let covered: Vec<_> = map.covered(&p([10, 0, 0, 0], 7)).map(|(_, &v)| v).collect();
+	assert_eq!(covered, [1, 2, 3, 4]);
+	let covered: Vec<_> = map
+		.covered(&p([10, 1, 0, 0], 16))
+		.map(|(_, &v)| v)
+		.collect();
+	assert_eq!(covered, [2, 3]);
+	let covered: Vec<_> = map
+		.covered(&p([10, 1, 0, 0], 20))
+		.map(|(_, &v)| v)
+		.collect();
+	assert_eq!(covered, [3]);
+	assert_eq!(map.covered(&p([10, 2, 0, 0], 16)).count(), 0);
+	assert_eq!(map.covered(&p([0, 0, 0, 0], 0)).count(), 6);
+}
+
+#[test]
+fn test_range() {
+	let mut next = lcg(0x9abc_def0);
+	let map: PrefixMap<_, _> = (0..500).map(|i| (random_prefix(&mut next), i)).collect();
+	let entries: Vec<_> = map.iter().collect();
+	for _ in 0..200 {
+		let (a, b) = (random_prefix(&mut next), random_prefix(&mut next));
+		let range: Vec<_> = map.range(a.clone()..b.clone()).collect();
+		let expected: Vec<_> = entries
+			.iter()
+			.copied()
+			.filter(|(k, _)| a <= **k && **k < b)
+			.collect();
+		assert_eq!(range, expected);
+		let range: Vec<_> = map
+			.range((Bound::Excluded(a.clone()), Bound::Included(b.clone())))
+			.collect();
+		let expected: Vec<_> = entries
+			.iter()
+			.copied()
+			.filter(|(k, _)| a < **k && **k <= b)
+			.collect();
+		assert_eq!(range, expected);
+
+		let mut range = map.range(a.clone()..);
+		range.seek(&b);
+		let expected = entries.iter().copied().find(|(k, _)| a <= **k && b <= **k);
+		assert_eq!(range.next(), expected);
+	}
+}
+
+#[test]
+fn test_persistent() {
+	let mut next = lcg(0x0bad_cafe);
+	let mut map = PersistentPrefixMap::new();
+	let mut reference = PrefixMap::new();
+	let mut snapshots = Vec::new();
+	for i in 0..500 {
+		let key = random_prefix(&mut next);
+		if next() % 3 == 0 {
+			assert_eq!(map.remove(&key), reference.remove(&key));
+		} else {
+			assert_eq!(map.insert(key.clone(), i), reference.insert(key, i));
+		}
+		if i % 50 == 0 {
+			snapshots.push((map.clone(), reference.clone()));
+		}
+	}
+	snapshots.push((map.clone(), reference));
+	for pair in snapshots.windows(2) {
+		let ((old, old_reference), (new, new_reference)) = (&pair[0], &pair[1]);
+		assert!(old.diff(new).eq(old_reference.diff(new_reference)));
+	}
+	for (map, reference) in snapshots {
+		assert_eq!(map.len(), reference.len());
+		assert!(map.iter().eq(reference.iter()));
+		let key = Prefix::new(Ipv4Addr::new(160, 160, 0, 0), 32);
+		assert_eq!(map.longest_match(&key), reference.longest_match(&key));
+		let (a, b) = (random_prefix(&mut next), random_prefix(&mut next));
+		assert!(map.covered(&a).eq(reference.covered(&a)));
+		assert!(map.range(a.clone()..=b.clone()).eq(reference.range(a..=b)));
+	}
+
+	let updated = map.update(p([10, 0, 0, 0], 8), 1000);
+	assert_eq!(updated.get(&p([10, 0, 0, 0], 8)), Some(&1000));
+	assert_ne!(map.get(&p([10, 0, 0, 0], 8)), Some(&1000));
+	assert_eq!(
+		updated
+			.without(&p([10, 0, 0, 0], 8))
+			.get(&p([10, 0, 0, 0], 8)),
+		None
+	);
+	assert!(map.without(&p([1, 2, 3, 4], 32)).ptr_eq(&map));
+}
+
+#[test]
+fn test_persistent_diff() {
+	let mut next = lcg(0x5eed_1234);
+	let old: PersistentPrefixMap<_, _> =
+		(0..10_000).map(|i| (random_prefix(&mut next), i)).collect();
+	let key = old.keys().nth(old.len() / 2).unwrap().clone();
+	let changed = old.update(key.clone(), u32::MAX);
+	let events: Vec<_> = old.diff(&changed).collect();
+	assert_eq!(events.len(), 1);
+	assert_eq!(
+		events[0],
+		DiffEvent::Changed(&key, old.get(&key).unwrap(), &u32::MAX)
+	);
+	let removed = old.without(&key);
+	assert!(removed
+		.diff(&old)
+		.eq([DiffEvent::Added(&key, old.get(&key).unwrap())]));
+	assert_eq!(old.diff(&old.clone()).count(), 0);
+}
+
+/// Bit string of only zeros; cheap to compare, so a long chain of
+/// nested keys builds quickly
+#[derive(Clone, PartialEq, Eq, Debug)]
+struct Zeros(usize);
+
+impl BitString for Zeros {
+	fn get(&self, ndx: usize) -> bool {
+		assert!(ndx < self.0);
+		false
+	}
+
+	fn set(&mut self, ndx: usize, bit: bool) {
+		assert!(ndx < self.0 && !bit);
+	}
+
+	fn flip(&mut self, _ndx: usize) {
+		unimplemented!()
+	}
+
+	fn len(&self) -> usize {
+		self.0
+	}
+
+	fn clip(&mut self, len: usize) {
+		self.0 = self.0.min(len);
+	}
+
+	fn append(&mut self, bit: bool) {
+		assert!(!bit);
+		self.0 += 1;
+	}
+
+	fn null() -> Self {
+		Zeros(0)
+	}
+
+	fn shared_prefix_len(&self, other: &Self) -> usize {
+		self.0.min(other.0)
+	}
+}
+
+#[test]
+fn test_deep_trie() {
+	const DEPTH: usize = 100_000;
+	let mut map = PrefixMap::new();
+	let mut persistent = PersistentPrefixMap::new();
+	for len in (0..DEPTH).rev() {
+		map.insert(Zeros(len), len);
+		persistent.insert(Zeros(len), len);
+	}
+	assert_eq!(map.remove(&Zeros(DEPTH / 2)), Some(DEPTH / 2));
+	assert_eq!(map.remove(&Zeros(DEPTH)), None);
+	match map.entry(Zeros(DEPTH - 1)) {
+		Entry::Occupied(entry) => assert_eq!(entry.remove(), DEPTH - 1),
+		Entry::Vacant(_) => panic!("missing deepest key"),
+	}
+	assert_eq!(map.len(), DEPTH - 2);
+	assert_eq!(persistent.remove(&Zeros(DEPTH / 2)), Some(DEPTH / 2));
+	drop(map);
+	drop(persistent);
+}
@@ -0,0 +1,58 @@
+use core::{
+	fmt,
+	marker::PhantomData,
+};
+
+use serde::{
+	de,
+	Deserialize,
+	Deserializer,
+	Serialize,
+	Serializer,
+};
+
+use crate::BigEndianBytes;
+
+/// Serialized as byte string (in all formats)
+impl<const N: usize> Serialize for BigEndianBytes<N> {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serializer.serialize_bytes(&self.0)
+	}
+}
+
+impl<'de, const N: usize> Deserialize<'de> for BigEndianBytes<N> {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		deserializer.deserialize_bytes(BytesVisitor(PhantomData))
+	}
+}
+
+struct BytesVisitor<const N: usize>(PhantomData<[u8; N]>);
+
+impl<'de, const N: usize> de::Visitor<'de> for BytesVisitor<N> {
+	type Value = BigEndianBytes<N>;
+
+	fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "{N} bytes")
+	}
+
+	fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+		let bytes = <[u8; N]>::try_from(v).map_err(|_| E::invalid_length(v.len(), &self))?;
+		Ok(BigEndianBytes(bytes))
+	}
+
+	fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+		let mut bytes = [0u8; N];
+		let mut len = 0;
+		while let Some(byte) = seq.next_element::<u8>()? {
+			if len == N {
+				return Err(de::Error::invalid_length(len + 1, &self));
+			}
+			bytes[len] = byte;
+			len += 1;
+		}
+		if len != N {
+			return Err(de::Error::invalid_length(len, &self));
+		}
+		Ok(BigEndianBytes(bytes))
+	}
+}
@@ -0,0 +1,283 @@
+//! [`Serialize`] and [`Deserialize`] for [`BitLengthString`],
+//! [`BigEndianBytes`] and [`IpAddressOrRange`].
+//!
+//! Human-readable formats use a string: `addr/len` if the bits
+//! themselves serialize as a string (like network addresses), otherwise
+//! the hex notation of [`text`](crate::text) (e.g. `b0/5`).
+//!
+//! Other formats use a byte string: the prefix length (big-endian, in as
+//! few bytes as needed for the longest prefix; a single byte for network
+//! addresses) followed by the `ceil(len / 8)` bytes covering the prefix.
+//! If this might not fit into a small stack buffer the bytes are
+//! serialized as sequence instead.
+//!
+//! Deserializing rejects prefixes with bits set after the prefix length.
+//!
+//! [`BigEndianBytes`]: crate::BigEndianBytes
+//! [`IpAddressOrRange`]: crate::der::rfc3779::IpAddressOrRange
+
+use core::{
+	fmt,
+	marker::PhantomData,
+};
+
+use serde::{
+	de::{
+		self,
+		value::StrDeserializer,
+		Unexpected,
+	},
+	ser::SerializeSeq,
+	Deserialize,
+	Deserializer,
+	Serialize,
+	Serializer,
+};
+
+use crate::{
+	text,
+	BitLengthString,
+	FixedBitString,
+};
+
+mod big_endian_bytes;
+#[cfg(all(feature = "alloc", feature = "net"))]
+mod rfc3779;
+mod str_capture;
+
+#[cfg(all(feature = "alloc", feature = "net"))]
+#[cfg(test)]
+mod tests;
+
+/// longest compact encoding serialized as byte string
+const MAX_COMPACT_BYTES: usize = 64;
+
+/// number of bytes to encode the prefix length of `W` in compact form
+const fn len_bytes<W: FixedBitString>() -> usize {
+	let bits = (usize::BITS - W::LEN.leading_zeros()) as usize;
+	if bits == 0 {
+		1
+	} else {
+		bits.div_ceil(8)
+	}
+}
+
+/// whether the compact form of `W` gets serialized as byte string
+const fn compact_as_bytes<W: FixedBitString>() -> bool {
+	len_bytes::<W>() + W::LEN.div_ceil(8) <= MAX_COMPACT_BYTES
+}
+
+/// whether `W` serializes as string in human-readable formats
+fn uses_addr_notation<W: FixedBitString + Serialize>() -> bool {
+	str_capture::is_string(&W::new_all_false())
+}
+
+impl<W: FixedBitString + Serialize> Serialize for BitLengthString<W> {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		if serializer.is_human_readable() {
+			if uses_addr_notation::<W>() {
+				serializer.collect_str(&format_args!(
+					"{}/{}",
+					str_capture::AsString(self.bits()),
+					self.len()
+				))
+			} else {
+				serializer.collect_str(&text::Hex(self))
+			}
+		} else {
+			let len = self.len().to_be_bytes();
+			let len = &len[len.len() - len_bytes::<W>()..];
+			let data = (0..self.len().div_ceil(8)).map(|ndx| {
+				(0..8).fold(0u8, |byte, bit| {
+					let ndx = 8 * ndx + bit;
+					(byte << 1) | (ndx < self.len() && self.bits().get(ndx)) as u8
+				})
+			});
+			if compact_as_bytes::<W>() {
+				let mut buf = [0u8; MAX_COMPACT_BYTES];
+				let mut used = 0;
+				for (slot, byte) in buf.iter_mut().zip(len.iter().copied().chain(data)) {
+					*slot = byte;
+					used += 1;
+				}
+				serializer.serialize_bytes(&buf[..used])
+			} else {
+				let mut seq = serializer.serialize_seq(Some(len.len() + self.len().div_ceil(8)))?;
+				for byte in len.iter().copied().chain(data) {
+					seq.serialize_element(&byte)?;
+				}
+				seq.end()
+			}
+		}
+	}
+}
+
+impl<'de, W: FixedBitString + Serialize + Deserialize<'de>> Deserialize<'de>
+	for BitLengthString<W>
+{
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		if deserializer.is_human_readable() {
+			deserializer.deserialize_str(ReadableVisitor {
+				address: uses_addr_notation::<W>(),
+				_marker: PhantomData,
+			})
+		} else if compact_as_bytes::<W>() {
+			deserializer.deserialize_bytes(CompactVisitor(PhantomData))
+		} else {
+			deserializer.deserialize_seq(CompactVisitor(PhantomData))
+		}
+	}
+}
+
+fn invalid_len<E: de::Error>(len: usize) -> E {
+	E::invalid_value(
+		Unexpected::Unsigned(len as u64),
+		&"a prefix length not exceeding the bit string length",
+	)
+}
+
+fn host_bits_set<E: de::Error>(len: usize) -> E {
+	E::custom(format_args!("host bits set after prefix length {len}"))
+}
+
+struct ReadableVisitor<W> {
+	/// `addr/len` notation instead of hex
+	address: bool,
+	_marker: PhantomData<W>,
+}
+
+impl<'de, W: FixedBitString + Deserialize<'de>> de::Visitor<'de> for ReadableVisitor<W> {
+	type Value = BitLengthString<W>;
+
+	fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		if self.address {
+			write!(
+				f,
+				"an address prefix with up to {} bits in `addr/len` notation",
+				W::LEN
+			)
+		} else {
+			write!(f, "a bit string with up to {} bits in hex notation", W::LEN)
+		}
+	}
+
+	fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+		let invalid = || E::invalid_value(Unexpected::Str(v), &self);
+		if !self.address {
+			return text::parse_hex(v).map_err(|err| match err {
+				text::ParseError::NonZeroPadding => E::custom(err),
+				_ => invalid(),
+			});
+		}
+		let (addr, len) = v.split_once('/').ok_or_else(invalid)?;
+		// `usize::from_str` would also accept a leading `+`
+		if len.is_empty() || !len.bytes().all(|c| c.is_ascii_digit()) {
+			return Err(invalid());
+		}
+		let addr = W::deserialize(StrDeserializer::<E>::new(addr)).map_err(|_| invalid())?;
+		let len = len.parse::<usize>().map_err(|_| invalid())?;
+		if len > W::LEN {
+			return Err(invalid_len(len));
+		}
+		if !addr.is_false_from(len) {
+			return Err(host_bits_set(len));
+		}
+		Ok(BitLengthString::new(addr, len))
+	}
+}
+
+/// Incremental decoder of the compact form
+struct CompactDecoder<W> {
+	/// bytes of the prefix length still to read
+	len_bytes: usize,
+	len: usize,
+	bits: W,
+	/// number of data bytes read
+	data: usize,
+}
+
+impl<W: FixedBitString> CompactDecoder<W> {
+	fn new() -> Self {
+		CompactDecoder {
+			len_bytes: len_bytes::<W>(),
+			len: 0,
+			bits: W::new_all_false(),
+			data: 0,
+		}
+	}
+
+	/// number of bytes consumed so far
+	fn consumed(&self) -> usize {
+		len_bytes::<W>() - self.len_bytes + self.data
+	}
+
+	fn push<E: de::Error>(&mut self, byte: u8, exp: &dyn de::Expected) -> Result<(), E> {
+		if self.len_bytes > 0 {
+			self.len_bytes -= 1;
+			self.len = (self.len << 8) | byte as usize;
+			if self.len_bytes == 0 && self.len > W::LEN {
+				return Err(invalid_len(self.len));
+			}
+			return Ok(());
+		}
+		if self.is_complete() {
+			return Err(E::invalid_length(self.consumed() + 1, exp));
+		}
+		for bit in 0..8 {
+			if byte & (0x80 >> bit) != 0 {
+				let ndx = 8 * self.data + bit;
+				if ndx >= self.len {
+					return Err(host_bits_set(self.len));
+				}
+				self.bits.set(ndx, true);
+			}
+		}
+		self.data += 1;
+		Ok(())
+	}
+
+	/// whether all bytes of the prefix were read
+	fn is_complete(&self) -> bool {
+		self.len_bytes == 0 && self.data == self.len.div_ceil(8)
+	}
+
+	fn finish<E: de::Error>(self, exp: &dyn de::Expected) -> Result<BitLengthString<W>, E> {
+		if !self.is_complete() {
+			return Err(E::invalid_length(self.consumed(), exp));
+		}
+		Ok(BitLengthString::new(self.bits, self.len))
+	}
+}
+
+struct CompactVisitor<W>(PhantomData<W>);
+
+impl<'de, W: FixedBitString> de::Visitor<'de> for CompactVisitor<W> {
+	type Value = BitLengthString<W>;
+
+	fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(
+			f,
+			"a prefix with up to {} bits as length and covered bytes",
+			W::LEN
+		)
+	}
+
+	fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+		let mut decoder = CompactDecoder::new();
+		for &byte in v {
+			if decoder.is_complete() {
+				return Err(E::invalid_length(v.len(), &self));
+			}
+			decoder.push(byte, &self)?;
+		}
+		decoder.finish(&self)
+	}
+
+	fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+		let mut decoder = CompactDecoder::new();
+		while let Some(byte) = seq.next_element::<u8>()? {
+			decoder.push(byte, &self)?;
+		}
+		decoder.finish(&self)
+	}
+}
@@ -0,0 +1,138 @@
+use core::{
+	fmt,
+	marker::PhantomData,
+};
+
+use serde::{
+	de::{
+		self,
+		EnumAccess,
+		VariantAccess,
+	},
+	ser::SerializeTupleVariant,
+	Deserialize,
+	Deserializer,
+	Serialize,
+	Serializer,
+};
+
+use crate::{
+	der::rfc3779::IpAddressOrRange,
+	interval_map::range_prefixes::cmp_bits,
+	BitLengthString,
+	FixedBitString,
+};
+
+const NAME: &str = "IpAddressOrRange";
+const VARIANTS: &[&str] = &["Prefix", "Range"];
+
+/// Serialized as enum with a `Prefix` newtype variant and a `Range`
+/// tuple variant of the first and last address
+impl<W: FixedBitString + Serialize> Serialize for IpAddressOrRange<W> {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		match self {
+			IpAddressOrRange::Prefix(prefix) => {
+				serializer.serialize_newtype_variant(NAME, 0, VARIANTS[0], prefix)
+			},
+			IpAddressOrRange::Range(first, last) => {
+				let mut range = serializer.serialize_tuple_variant(NAME, 1, VARIANTS[1], 2)?;
+				range.serialize_field(first)?;
+				range.serialize_field(last)?;
+				range.end()
+			},
+		}
+	}
+}
+
+/// Rejects ranges with the first address after the last address
+impl<'de, W: FixedBitString + Serialize + Deserialize<'de>> Deserialize<'de>
+	for IpAddressOrRange<W>
+{
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		deserializer.deserialize_enum(NAME, VARIANTS, EnumVisitor(PhantomData))
+	}
+}
+
+enum Variant {
+	Prefix,
+	Range,
+}
+
+impl<'de> Deserialize<'de> for Variant {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		deserializer.deserialize_identifier(VariantVisitor)
+	}
+}
+
+struct VariantVisitor;
+
+impl de::Visitor<'_> for VariantVisitor {
+	type Value = Variant;
+
+	fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str("variant identifier")
+	}
+
+	fn visit_u64<E: de::Error>(self, v: u64) -> Result<Variant, E> {
+		match v {
+			0 => Ok(Variant::Prefix),
+			1 => Ok(Variant::Range),
+			_ => Err(E::invalid_value(
+				de::Unexpected::Unsigned(v),
+				&"variant index 0 <= i < 2",
+			)),
+		}
+	}
+
+	fn visit_str<E: de::Error>(self, v: &str) -> Result<Variant, E> {
+		match v {
+			"Prefix" => Ok(Variant::Prefix),
+			"Range" => Ok(Variant::Range),
+			_ => Err(E::unknown_variant(v, VARIANTS)),
+		}
+	}
+}
+
+struct EnumVisitor<W>(PhantomData<W>);
+
+impl<'de, W: FixedBitString + Serialize + Deserialize<'de>> de::Visitor<'de> for EnumVisitor<W> {
+	type Value = IpAddressOrRange<W>;
+
+	fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str("an address prefix or range")
+	}
+
+	fn visit_enum<A: EnumAccess<'de>>(self, data: A) -> Result<Self::Value, A::Error> {
+		match data.variant()? {
+			(Variant::Prefix, variant) => Ok(IpAddressOrRange::Prefix(
+				variant.newtype_variant::<BitLengthString<W>>()?,
+			)),
+			(Variant::Range, variant) => variant.tuple_variant(2, RangeVisitor(PhantomData)),
+		}
+	}
+}
+
+struct RangeVisitor<W>(PhantomData<W>);
+
+impl<'de, W: FixedBitString + Deserialize<'de>> de::Visitor<'de> for RangeVisitor<W> {
+	type Value = IpAddressOrRange<W>;
+
+	fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str("first and last address of a range")
+	}
+
+	fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+		let first: W = seq
+			.next_element()?
+			.ok_or_else(|| de::Error::invalid_length(0, &self))?;
+		let last: W = seq
+			.next_element()?
+			.ok_or_else(|| de::Error::invalid_length(1, &self))?;
+		if cmp_bits(&first, &last).is_gt() {
+			return Err(de::Error::custom(
+				"first address of range after last address",
+			));
+		}
+		Ok(IpAddressOrRange::Range(first, last))
+	}
+}
@@ -0,0 +1,179 @@
+use core::fmt;
+
+use serde::{
+	ser::{
+		self,
+		Impossible,
+	},
+	Serialize,
+};
+
+/// Value doesn't serialize as a string in human-readable formats
+#[derive(Debug)]
+pub(super) struct NotAString;
+
+impl fmt::Display for NotAString {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str("value doesn't serialize as string")
+	}
+}
+
+impl ser::StdError for NotAString {}
+
+impl ser::Error for NotAString {
+	fn custom<T: fmt::Display>(_msg: T) -> Self {
+		NotAString
+	}
+}
+
+/// Whether `value` serializes as (a single) string in human-readable
+/// formats
+pub(super) fn is_string<T: Serialize + ?Sized>(value: &T) -> bool {
+	struct Discard;
+
+	impl fmt::Write for Discard {
+		fn write_str(&mut self, _s: &str) -> fmt::Result {
+			Ok(())
+		}
+	}
+
+	value.serialize(StrCapture(&mut Discard)).is_ok()
+}
+
+/// Display `value` through its human-readable string serialization;
+/// must only be used if [`is_string`] returned true.
+pub(super) struct AsString<'a, T: ?Sized>(pub(super) &'a T);
+
+impl<T: Serialize + ?Sized> fmt::Display for AsString<'_, T> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		self.0
+			.serialize(StrCapture(f))
+			.map_err(|NotAString| fmt::Error)
+	}
+}
+
+/// Serializer writing a string value into `W`; fails for everything
+/// else.
+struct StrCapture<'a, W: fmt::Write>(&'a mut W);
+
+macro_rules! not_a_string {
+	($($method:ident($($ty:ty),*);)*) => {
+		$(
+			fn $method(self, $(_: $ty),*) -> Result<(), NotAString> {
+				Err(NotAString)
+			}
+		)*
+	};
+}
+
+impl<W: fmt::Write> ser::Serializer for StrCapture<'_, W> {
+	type Error = NotAString;
+	type Ok = ();
+	type SerializeMap = Impossible<(), NotAString>;
+	type SerializeSeq = Impossible<(), NotAString>;
+	type SerializeStruct = Impossible<(), NotAString>;
+	type SerializeStructVariant = Impossible<(), NotAString>;
+	type SerializeTuple = Impossible<(), NotAString>;
+	type SerializeTupleStruct = Impossible<(), NotAString>;
+	type SerializeTupleVariant = Impossible<(), NotAString>;
+
+	not_a_string! {
+		serialize_bool(bool);
+		serialize_i8(i8);
+		serialize_i16(i16);
+		serialize_i32(i32);
+		serialize_i64(i64);
+		serialize_i128(i128);
+		serialize_u8(u8);
+		serialize_u16(u16);
+		serialize_u32(u32);
+		serialize_u64(u64);
+		serialize_u128(u128);
+		serialize_f32(f32);
+		serialize_f64(f64);
+		serialize_char(char);
+		serialize_bytes(&[u8]);
+		serialize_none();
+		serialize_unit();
+		serialize_unit_struct(&'static str);
+		serialize_unit_variant(&'static str, u32, &'static str);
+	}
+
+	fn serialize_str(self, v: &str) -> Result<(), NotAString> {
+		self.0.write_str(v).map_err(|_| NotAString)
+	}
+
+	fn collect_str<T: fmt::Display + ?Sized>(self, value: &T) -> Result<(), NotAString> {
+		write!(self.0, "{value}").map_err(|_| NotAString)
+	}
+
+	fn serialize_some<T: Serialize + ?Sized>(self, _value: &T) -> Result<(), NotAString> {
+		Err(NotAString)
+	}
+
+	fn serialize_newtype_struct<T: Serialize + ?Sized>(
+		self,
+		_name: &'static str,
+		value: &T,
+	) -> Result<(), NotAString> {
+		value.serialize(self)
+	}
+
+	fn serialize_newtype_variant<T: Serialize + ?Sized>(
+		self,
+		_name: &'static str,
+		_variant_index: u32,
+		_variant: &'static str,
+		_value: &T,
+	) -> Result<(), NotAString> {
+		Err(NotAString)
+	}
+
+	fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, NotAString> {
+		Err(NotAString)
+	}
+
+	fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, NotAString> {
+		Err(NotAString)
+	}
+
+	fn serialize_tuple_struct(
+		self,
+		_name: &'static str,
+		_len: usize,
+	) -> Result<Self::SerializeTupleStruct, NotAString> {
+		Err(NotAString)
+	}
+
+	fn serialize_tuple_variant(
+		self,
+		_name: &'static str,
+		_variant_index: u32,
+		_variant: &'static str,
+		_len: usize,
+	) -> Result<Self::SerializeTupleVariant, NotAString> {
+		Err(NotAString)
+	}
+
+	fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, NotAString> {
+		Err(NotAString)
+	}
+
+	fn serialize_struct(
+		self,
+		_name: &'static str,
+		_len: usize,
+	) -> Result<Self::SerializeStruct, NotAString> {
+		Err(NotAString)
+	}
+
+	fn serialize_struct_variant(
+		self,
+		_name: &'static str,
+		_variant_index: u32,
+		_variant: &'static str,
+		_len: usize,
+	) -> Result<Self::SerializeStructVariant, NotAString> {
+		Err(NotAString)
+	}
+}
@@ -0,0 +1,184 @@
+use core::net::{
+	Ipv4Addr,
+	Ipv6Addr,
+};
+
+use serde_test::{
+	assert_de_tokens,
+	assert_de_tokens_error,
+	assert_tokens,
+	Compact,
+	Configure,
+	Readable,
+	Token,
+};
+
+use crate::{
+	der::rfc3779::IpAddressOrRange,
+	BigEndianBytes,
+	BitLengthString,
+};
+
+type Ipv4Prefix = BitLengthString<Ipv4Addr>;
+type Ipv6Prefix = BitLengthString<Ipv6Addr>;
+
+fn p4(addr: [u8; 4], len: usize) -> Ipv4Prefix {
+	Ipv4Prefix::new(Ipv4Addr::from(addr), len)
+}
+
+fn p6(addr: &str, len: usize) -> Ipv6Prefix {
+	Ipv6Prefix::new(addr.parse().unwrap(), len)
+}
+
+#[test]
+fn test_readable() {
+	assert_tokens(
+		&p4([10, 0, 0, 0], 8).readable(),
+		&[Token::Str("10.0.0.0/8")],
+	);
+	assert_tokens(&p4([0, 0, 0, 0], 0).readable(), &[Token::Str("0.0.0.0/0")]);
+	assert_tokens(
+		&p6("2001:db8::", 32).readable(),
+		&[Token::Str("2001:db8::/32")],
+	);
+	assert_tokens(&p6("::1", 128).readable(), &[Token::Str("::1/128")]);
+
+	assert_de_tokens_error::<Readable<Ipv4Prefix>>(
+		&[Token::Str("10.0.0.1/8")],
+		"host bits set after prefix length 8",
+	);
+	assert_de_tokens_error::<Readable<Ipv4Prefix>>(
+		&[Token::Str("10.0.0.0/33")],
+		"invalid value: integer `33`, expected a prefix length not exceeding the bit string length",
+	);
+	for invalid in ["10.0.0.0", "10.0.0.0/", "10.0.0.0/+8", "10.0.0/8", "::/0"] {
+		assert_de_tokens_error::<Readable<Ipv4Prefix>>(
+			&[Token::Str(invalid)],
+			&alloc::format!(
+				"invalid value: string {invalid:?}, expected an address prefix with up to 32 bits in `addr/len` notation"
+			),
+		);
+	}
+}
+
+#[test]
+fn test_compact() {
+	assert_tokens(&p4([10, 0, 0, 0], 8).compact(), &[Token::Bytes(&[8, 10])]);
+	assert_tokens(&p4([0, 0, 0, 0], 0).compact(), &[Token::Bytes(&[0])]);
+	assert_tokens(
+		&p4([192, 168, 128, 0], 17).compact(),
+		&[Token::Bytes(&[17, 192, 168, 128])],
+	);
+	assert_tokens(
+		&p6("2001:db8::", 32).compact(),
+		&[Token::Bytes(&[32, 0x20, 0x01, 0x0d, 0xb8])],
+	);
+	assert_de_tokens(
+		&p4([10, 0, 0, 0], 8).compact(),
+		&[
+			Token::Seq { len: Some(2) },
+			Token::U8(8),
+			Token::U8(10),
+			Token::SeqEnd,
+		],
+	);
+
+	assert_de_tokens_error::<Compact<Ipv4Prefix>>(
+		&[Token::Bytes(&[8, 10, 0])],
+		"invalid length 3, expected a prefix with up to 32 bits as length and covered bytes",
+	);
+	assert_de_tokens_error::<Compact<Ipv4Prefix>>(
+		&[Token::Bytes(&[7, 11])],
+		"host bits set after prefix length 7",
+	);
+	assert_de_tokens_error::<Compact<Ipv4Prefix>>(
+		&[Token::Bytes(&[33, 1, 2, 3, 4, 5])],
+		"invalid value: integer `33`, expected a prefix length not exceeding the bit string length",
+	);
+}
+
+#[test]
+fn test_generic() {
+	let short = BitLengthString::new(BigEndianBytes::new([0xb0, 0, 0, 0]), 5);
+	assert_tokens(&short.clone().readable(), &[Token::Str("b0/5")]);
+	assert_tokens(&short.compact(), &[Token::Bytes(&[5, 0xb0])]);
+	assert_de_tokens_error::<Readable<BitLengthString<BigEndianBytes<4>>>>(
+		&[Token::Str("b4/5")],
+		"bits set after bit string length",
+	);
+	assert_de_tokens_error::<Readable<BitLengthString<BigEndianBytes<4>>>>(
+		&[Token::Str("0/33")],
+		"invalid value: string \"0/33\", expected a bit string with up to 32 bits in hex notation",
+	);
+
+	// too long for the stack buffer: sequence of bytes, 2 length bytes
+	let mut bytes = [0u8; 100];
+	bytes[..2].copy_from_slice(&[0xab, 0xc0]);
+	let long = BitLengthString::new(BigEndianBytes::new(bytes), 12);
+	assert_tokens(&long.clone().readable(), &[Token::Str("abc")]);
+	assert_tokens(
+		&long.compact(),
+		&[
+			Token::Seq { len: Some(4) },
+			Token::U8(0),
+			Token::U8(12),
+			Token::U8(0xab),
+			Token::U8(0xc0),
+			Token::SeqEnd,
+		],
+	);
+	assert_de_tokens_error::<Compact<BitLengthString<BigEndianBytes<100>>>>(
+		&[
+			Token::Seq { len: Some(3) },
+			Token::U8(3),
+			Token::U8(0x21),
+			Token::SeqEnd,
+		],
+		"invalid value: integer `801`, expected a prefix length not exceeding the bit string length",
+	);
+}
+
+#[test]
+fn test_address_or_range() {
+	let prefix = IpAddressOrRange::Prefix(p4([10, 0, 0, 0], 8));
+	assert_tokens(
+		&prefix.readable(),
+		&[
+			Token::NewtypeVariant {
+				name: "IpAddressOrRange",
+				variant: "Prefix",
+			},
+			Token::Str("10.0.0.0/8"),
+		],
+	);
+	let range = IpAddressOrRange::Range(
+		Ipv4Addr::new(192, 168, 0, 0),
+		Ipv4Addr::new(192, 168, 2, 255),
+	);
+	assert_tokens(
+		&range.readable(),
+		&[
+			Token::TupleVariant {
+				name: "IpAddressOrRange",
+				variant: "Range",
+				len: 2,
+			},
+			Token::Str("192.168.0.0"),
+			Token::Str("192.168.2.255"),
+			Token::TupleVariantEnd,
+		],
+	);
+	assert_de_tokens_error::<Readable<IpAddressOrRange<Ipv4Addr>>>(
+		&[
+			Token::TupleVariant {
+				name: "IpAddressOrRange",
+				variant: "Range",
+				len: 2,
+			},
+			Token::Str("192.168.2.255"),
+			Token::Str("192.168.0.0"),
+			Token::TupleVariantEnd,
+		],
+		"first address of range after last address",
+	);
+}
@@ -0,0 +1,9 @@
+//! Helpers shared by the unit tests of several modules.
+
+/// Simple LCG to generate pseudo random values
+pub(crate) fn lcg(mut state: u32) -> impl FnMut() -> u32 {
+	move || {
+		state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+		state
+	}
+}
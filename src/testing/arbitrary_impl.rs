@@ -0,0 +1,73 @@
+use arbitrary::{
+	Arbitrary,
+	Error,
+	Result,
+	Unstructured,
+};
+
+use crate::{
+	testing::{
+		Siblings,
+		Subnet,
+	},
+	BitLengthString,
+	FixedBitString,
+};
+
+/// Length in `0..=max`, biased towards edge cases
+fn biased_len(u: &mut Unstructured<'_>, max: usize) -> Result<usize> {
+	let len = match u.int_in_range(0..=3u8)? {
+		// near zero
+		0 => u.int_in_range(0..=max.min(2))?,
+		// near max
+		1 => max - u.int_in_range(0..=max.min(2))?,
+		// near a nibble / octet boundary
+		2 => {
+			let boundary = 4 * u.int_in_range(0..=max / 4)?;
+			(boundary + u.int_in_range(0..=2)?)
+				.saturating_sub(1)
+				.min(max)
+		},
+		_ => u.int_in_range(0..=max)?,
+	};
+	Ok(len)
+}
+
+fn bits<W: FixedBitString>(u: &mut Unstructured<'_>) -> Result<W> {
+	let mut bits = W::new_all_false();
+	for start in (0..W::LEN).step_by(8) {
+		let byte = u8::arbitrary(u)?;
+		for ndx in start..W::LEN.min(start + 8) {
+			if 0 != byte & (0x80 >> (ndx - start)) {
+				bits.set(ndx, true);
+			}
+		}
+	}
+	Ok(bits)
+}
+
+impl<'a, W: FixedBitString> Arbitrary<'a> for BitLengthString<W> {
+	fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+		let len = biased_len(u, W::LEN)?;
+		Ok(BitLengthString::new(bits(u)?, len))
+	}
+}
+
+impl<'a, W: FixedBitString + Clone> Arbitrary<'a> for Subnet<W> {
+	fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+		// no subnets of zero length bit strings
+		let max_prefix_len = W::LEN.checked_sub(1).ok_or(Error::IncorrectFormat)?;
+		let prefix_len = biased_len(u, max_prefix_len)?;
+		let subnet_len = prefix_len + 1 + biased_len(u, W::LEN - prefix_len - 1)?;
+		Ok(Subnet::new(bits(u)?, prefix_len, subnet_len))
+	}
+}
+
+impl<'a, W: FixedBitString + Clone> Arbitrary<'a> for Siblings<W> {
+	fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+		// no siblings of zero length bit strings
+		let max_len = W::LEN.checked_sub(1).ok_or(Error::IncorrectFormat)?;
+		let len = 1 + biased_len(u, max_len)?;
+		Ok(Siblings::new(bits(u)?, len))
+	}
+}
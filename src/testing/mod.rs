@@ -0,0 +1,82 @@
+//! Generating bit strings for property tests and fuzzing.
+//!
+//! With the `proptest` feature [`BitLengthString`], [`Subnet`] and
+//! [`Siblings`] implement `proptest::arbitrary::Arbitrary`, and there
+//! are strategies for [`FixedBitString`] values and prefix lengths.
+//! With the `arbitrary` feature they implement
+//! `arbitrary::Arbitrary`.
+//!
+//! Both work for any [`FixedBitString`] (not only network addresses),
+//! and prefer lengths that tend to hit edge cases: lengths near `0` and
+//! near `W::LEN`, and lengths near multiples of 4 (nibble and octet
+//! boundaries).
+
+use crate::{
+	BitLengthString,
+	FixedBitString,
+};
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary_impl;
+#[cfg(feature = "proptest")]
+mod proptest_impl;
+
+#[cfg(feature = "net")]
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "proptest")]
+pub use self::proptest_impl::{
+	fixed_bit_string,
+	prefix_len,
+};
+
+/// A prefix and one of its (strictly longer) subnets
+///
+/// Can't be generated for bit strings of length zero: `arbitrary`
+/// fails with `IncorrectFormat`, the `proptest` strategy panics.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Subnet<W: FixedBitString> {
+	/// the shorter prefix
+	pub prefix: BitLengthString<W>,
+	/// subnet of `prefix`; longer than `prefix`
+	pub subnet: BitLengthString<W>,
+}
+
+/// Two different prefixes with the same parent prefix, i.e. the same
+/// length and only differing in the last bit
+///
+/// Can't be generated for bit strings of length zero: `arbitrary`
+/// fails with `IncorrectFormat`, the `proptest` strategy panics.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Siblings<W: FixedBitString> {
+	/// sibling with last bit `false`
+	pub lower: BitLengthString<W>,
+	/// sibling with last bit `true`
+	pub upper: BitLengthString<W>,
+}
+
+impl<W: FixedBitString + Clone> Subnet<W> {
+	/// `prefix` must be shorter than `subnet_len`
+	fn new(bits: W, prefix_len: usize, subnet_len: usize) -> Self {
+		debug_assert!(prefix_len < subnet_len);
+		Subnet {
+			prefix: BitLengthString::new(bits.clone(), prefix_len),
+			subnet: BitLengthString::new(bits, subnet_len),
+		}
+	}
+}
+
+impl<W: FixedBitString + Clone> Siblings<W> {
+	/// `len` must not be zero
+	fn new(mut bits: W, len: usize) -> Self {
+		debug_assert!(len > 0);
+		bits.set(len - 1, false);
+		let lower = BitLengthString::new(bits.clone(), len);
+		bits.set(len - 1, true);
+		Siblings {
+			lower,
+			upper: BitLengthString::new(bits, len),
+		}
+	}
+}
@@ -0,0 +1,95 @@
+use core::fmt;
+
+use proptest::{
+	arbitrary::Arbitrary,
+	collection::vec,
+	prelude::*,
+	strategy::BoxedStrategy,
+};
+
+use crate::{
+	testing::{
+		Siblings,
+		Subnet,
+	},
+	BitLengthString,
+	FixedBitString,
+};
+
+/// Strategy for arbitrary values of any [`FixedBitString`]
+///
+/// Shrinks towards all bits `false`.
+pub fn fixed_bit_string<W>() -> impl Strategy<Value = W>
+where
+	W: FixedBitString + fmt::Debug,
+{
+	vec(any::<bool>(), W::LEN).prop_map(|bits| {
+		let mut value = W::new_all_false();
+		for (ndx, bit) in bits.into_iter().enumerate() {
+			value.set(ndx, bit);
+		}
+		value
+	})
+}
+
+/// Strategy for lengths in `0..=max`, biased towards edge cases: lengths
+/// near `0`, near `max` and near multiples of 4 (nibble and octet
+/// boundaries)
+pub fn prefix_len(max: usize) -> impl Strategy<Value = usize> {
+	prop_oneof![
+		0..=max.min(2),
+		max.saturating_sub(2)..=max,
+		(0..=max / 4, 0..=2usize)
+			.prop_map(move |(n, offset)| (4 * n + offset).saturating_sub(1).min(max)),
+		0..=max,
+	]
+}
+
+impl<W> Arbitrary for BitLengthString<W>
+where
+	W: FixedBitString + Clone + fmt::Debug + 'static,
+{
+	type Parameters = ();
+	type Strategy = BoxedStrategy<Self>;
+
+	fn arbitrary_with(_args: ()) -> Self::Strategy {
+		(fixed_bit_string(), prefix_len(W::LEN))
+			.prop_map(|(bits, len)| BitLengthString::new(bits, len))
+			.boxed()
+	}
+}
+
+impl<W> Arbitrary for Subnet<W>
+where
+	W: FixedBitString + Clone + fmt::Debug + 'static,
+{
+	type Parameters = ();
+	type Strategy = BoxedStrategy<Self>;
+
+	fn arbitrary_with(_args: ()) -> Self::Strategy {
+		assert!(W::LEN > 0, "no subnets of zero length bit strings");
+		(fixed_bit_string(), prefix_len(W::LEN - 1))
+			.prop_flat_map(|(bits, len)| {
+				let subnet_len =
+					prefix_len(W::LEN - len - 1).prop_map(move |extra| len + 1 + extra);
+				(Just(bits), Just(len), subnet_len)
+			})
+			.prop_map(|(bits, len, subnet_len)| Subnet::new(bits, len, subnet_len))
+			.boxed()
+	}
+}
+
+impl<W> Arbitrary for Siblings<W>
+where
+	W: FixedBitString + Clone + fmt::Debug + 'static,
+{
+	type Parameters = ();
+	type Strategy = BoxedStrategy<Self>;
+
+	fn arbitrary_with(_args: ()) -> Self::Strategy {
+		assert!(W::LEN > 0, "no siblings of zero length bit strings");
+		(fixed_bit_string(), prefix_len(W::LEN - 1))
+			.prop_map(|(bits, len)| Siblings::new(bits, len + 1))
+			.boxed()
+	}
+}
@@ -0,0 +1,127 @@
+use core::cmp::Ordering;
+
+use crate::{
+	testing::{
+		Siblings,
+		Subnet,
+	},
+	BigEndianBytes,
+	BitLengthString,
+	BitString,
+	FixedBitString,
+};
+
+fn check_prefix<W: FixedBitString>(prefix: &BitLengthString<W>) {
+	assert!(prefix.len() <= W::LEN);
+	assert!(prefix.bits().is_false_from(prefix.len()));
+}
+
+fn check_subnet<W: FixedBitString>(pair: &Subnet<W>) {
+	check_prefix(&pair.prefix);
+	check_prefix(&pair.subnet);
+	assert_eq!(pair.subnet.subset_cmp(&pair.prefix), Some(Ordering::Less));
+}
+
+fn check_siblings<W: FixedBitString>(pair: &Siblings<W>) {
+	check_prefix(&pair.lower);
+	check_prefix(&pair.upper);
+	let len = pair.lower.len();
+	assert_eq!(pair.upper.len(), len);
+	assert_eq!(pair.lower.shared_prefix_len(&pair.upper), len - 1);
+	assert!(!pair.lower.get(len - 1));
+	assert!(pair.upper.get(len - 1));
+}
+
+#[cfg(feature = "proptest")]
+mod proptest_tests {
+	use core::net::{
+		Ipv4Addr,
+		Ipv6Addr,
+	};
+
+	use proptest::prelude::*;
+
+	use super::*;
+	use crate::testing::prefix_len;
+
+	proptest! {
+		#[test]
+		fn prefixes(v4 in any::<BitLengthString<Ipv4Addr>>(), v6 in any::<BitLengthString<Ipv6Addr>>()) {
+			check_prefix(&v4);
+			check_prefix(&v6);
+		}
+
+		#[test]
+		fn pairs(subnet in any::<Subnet<Ipv6Addr>>(), siblings in any::<Siblings<Ipv4Addr>>()) {
+			check_subnet(&subnet);
+			check_siblings(&siblings);
+		}
+
+		#[test]
+		fn lengths(len in prefix_len(32)) {
+			prop_assert!(len <= 32);
+		}
+
+		#[test]
+		fn empty(prefix in any::<BitLengthString<BigEndianBytes<0>>>()) {
+			prop_assert_eq!(prefix.len(), 0);
+		}
+	}
+
+	#[test]
+	#[should_panic = "no subnets of zero length bit strings"]
+	fn empty_subnet() {
+		let _ = any::<Subnet<BigEndianBytes<0>>>();
+	}
+
+	#[test]
+	#[should_panic = "no siblings of zero length bit strings"]
+	fn empty_siblings() {
+		let _ = any::<Siblings<BigEndianBytes<0>>>();
+	}
+}
+
+#[cfg(feature = "arbitrary")]
+#[test]
+fn test_arbitrary() {
+	use core::net::{
+		Ipv4Addr,
+		Ipv6Addr,
+	};
+
+	use arbitrary::{
+		Arbitrary,
+		Unstructured,
+	};
+
+	let mut data = [0u8; 4096];
+	let mut state = 0x1234_5678u32;
+	for byte in data.iter_mut() {
+		state = state.wrapping_mul(1_103_515_245).wrapping_add(12345);
+		*byte = (state >> 16) as u8;
+	}
+	let mut u = Unstructured::new(&data);
+	let mut seen = [false; 33];
+	for _ in 0..100 {
+		let prefix = BitLengthString::<Ipv4Addr>::arbitrary(&mut u).unwrap();
+		check_prefix(&prefix);
+		seen[prefix.len()] = true;
+		check_prefix(&BitLengthString::<Ipv6Addr>::arbitrary(&mut u).unwrap());
+		check_subnet(&Subnet::<Ipv4Addr>::arbitrary(&mut u).unwrap());
+		check_siblings(&Siblings::<Ipv6Addr>::arbitrary(&mut u).unwrap());
+	}
+	// biased towards edge cases
+	assert!(seen[0] && seen[32] && seen[8] && seen[24]);
+
+	// exhausted input still generates valid values
+	let mut u = Unstructured::new(&[]);
+	check_subnet(&Subnet::<Ipv4Addr>::arbitrary(&mut u).unwrap());
+	check_siblings(&Siblings::<Ipv4Addr>::arbitrary(&mut u).unwrap());
+
+	// zero length bit strings only have the empty prefix
+	let mut u = Unstructured::new(&data);
+	let empty = BitLengthString::<BigEndianBytes<0>>::arbitrary(&mut u).unwrap();
+	assert_eq!(empty.len(), 0);
+	assert!(Subnet::<BigEndianBytes<0>>::arbitrary(&mut u).is_err());
+	assert!(Siblings::<BigEndianBytes<0>>::arbitrary(&mut u).is_err());
+}
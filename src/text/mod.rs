@@ -0,0 +1,230 @@
+//! Textual representation of bit strings.
+//!
+//! Two formats are supported:
+//!
+//! - binary: one `0` or `1` per bit, e.g. `10110`
+//! - hex: one hex digit per 4 bits; if the length isn't a multiple of 4
+//!   the last digit is padded with `false` bits and the length follows
+//!   after a `/`, e.g. `b0/5`
+//!
+//! Both formats can be prefixed with `0b` or `0x` respectively; the
+//! [`Display`] wrappers [`Binary`] and [`Hex`] write the prefix in
+//! alternate mode (`{:#}`).
+//!
+//! When parsing hex the length can also be up to 3 bits larger than the
+//! bits given by the digits; missing bits are `false` (e.g. `b/5` is the
+//! same as `b0/5`).
+//! Bits after the length must be `false`.
+//!
+//! Writing and parsing don't allocate; [`write_binary`] and
+//! [`write_hex`] work with any [`fmt::Write`].
+//!
+//! [`Display`]: fmt::Display
+
+use core::{
+	fmt::{
+		self,
+		Write,
+	},
+	str::FromStr,
+};
+
+use crate::BitString;
+
+#[cfg(all(feature = "alloc", feature = "net"))]
+#[cfg(test)]
+mod tests;
+
+/// Error when parsing a bit string
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ParseError {
+	/// invalid character at the given byte offset
+	InvalidDigit(usize),
+	/// invalid length after `/`
+	InvalidLength,
+	/// missing `0b` or `0x` prefix
+	MissingPrefix,
+	/// bits after the length are not `false`
+	NonZeroPadding,
+	/// bit string is too long for the target type
+	TooLong,
+}
+
+impl fmt::Display for ParseError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			ParseError::InvalidDigit(pos) => write!(f, "invalid digit at offset {pos}"),
+			ParseError::InvalidLength => f.write_str("invalid bit string length"),
+			ParseError::MissingPrefix => f.write_str("missing `0b` or `0x` prefix"),
+			ParseError::NonZeroPadding => f.write_str("bits set after bit string length"),
+			ParseError::TooLong => f.write_str("bit string too long"),
+		}
+	}
+}
+
+impl core::error::Error for ParseError {}
+
+/// Write bits as `0` and `1` characters
+pub fn write_binary<B: BitString, W: Write>(w: &mut W, bits: &B) -> fmt::Result {
+	for ndx in 0..bits.len() {
+		w.write_char(if bits.get(ndx) { '1' } else { '0' })?;
+	}
+	Ok(())
+}
+
+/// Write bits as hex digits; appends `/` and the length if the length
+/// isn't a multiple of 4
+pub fn write_hex<B: BitString, W: Write>(w: &mut W, bits: &B) -> fmt::Result {
+	let len = bits.len();
+	for start in (0..len).step_by(4) {
+		let mut nibble = 0;
+		for ndx in start..start + 4 {
+			nibble <<= 1;
+			if ndx < len && bits.get(ndx) {
+				nibble |= 1;
+			}
+		}
+		w.write_char(char::from_digit(nibble, 16).expect("nibble"))?;
+	}
+	if len % 4 != 0 {
+		write!(w, "/{len}")?;
+	}
+	Ok(())
+}
+
+fn append<B: BitString>(bits: &mut B, bit: bool) -> Result<(), ParseError> {
+	if bits.max_len().is_some_and(|max_len| bits.len() >= max_len) {
+		return Err(ParseError::TooLong);
+	}
+	bits.append(bit);
+	Ok(())
+}
+
+/// Parse bits from `0` and `1` characters with optional `0b` prefix
+pub fn parse_binary<B: BitString>(s: &str) -> Result<B, ParseError> {
+	let offset = if s.starts_with("0b") { 2 } else { 0 };
+	parse_binary_digits(s, offset)
+}
+
+fn parse_binary_digits<B: BitString>(s: &str, offset: usize) -> Result<B, ParseError> {
+	let mut bits = B::null();
+	for (pos, c) in s.bytes().enumerate().skip(offset) {
+		match c {
+			b'0' => append(&mut bits, false)?,
+			b'1' => append(&mut bits, true)?,
+			_ => return Err(ParseError::InvalidDigit(pos)),
+		}
+	}
+	Ok(bits)
+}
+
+/// Parse bits from hex digits (optionally followed by `/` and the
+/// length) with optional `0x` prefix
+pub fn parse_hex<B: BitString>(s: &str) -> Result<B, ParseError> {
+	let offset = if s.starts_with("0x") { 2 } else { 0 };
+	parse_hex_digits(s, offset)
+}
+
+fn parse_hex_digits<B: BitString>(s: &str, offset: usize) -> Result<B, ParseError> {
+	let (digits, len) = match s.split_once('/') {
+		Some((digits, len)) => {
+			// `usize::from_str` would also accept a leading `+`
+			if len.is_empty() || !len.bytes().all(|c| c.is_ascii_digit()) {
+				return Err(ParseError::InvalidLength);
+			}
+			let len = len
+				.parse::<usize>()
+				.map_err(|_| ParseError::InvalidLength)?;
+			(digits, Some(len))
+		},
+		None => (s, None),
+	};
+	let supplied = 4 * (digits.len() - offset);
+	let len = len.unwrap_or(supplied);
+	let mut bits = B::null();
+	if bits.max_len().is_some_and(|max_len| len > max_len) {
+		return Err(ParseError::TooLong);
+	}
+	// only the padding of the last digit may be missing
+	if len > supplied + 3 {
+		return Err(ParseError::InvalidLength);
+	}
+	for (pos, c) in digits.bytes().enumerate().skip(offset) {
+		let nibble = char::from(c)
+			.to_digit(16)
+			.ok_or(ParseError::InvalidDigit(pos))?;
+		for shift in (0..4).rev() {
+			let bit = 0 != nibble & (1 << shift);
+			if bits.len() < len {
+				append(&mut bits, bit)?;
+			} else if bit {
+				return Err(ParseError::NonZeroPadding);
+			}
+		}
+	}
+	while bits.len() < len {
+		append(&mut bits, false)?;
+	}
+	Ok(bits)
+}
+
+/// Parse bits in binary (`0b` prefix) or hex (`0x` prefix) format
+pub fn parse_prefixed<B: BitString>(s: &str) -> Result<B, ParseError> {
+	if s.starts_with("0b") {
+		parse_binary_digits(s, 2)
+	} else if s.starts_with("0x") {
+		parse_hex_digits(s, 2)
+	} else {
+		Err(ParseError::MissingPrefix)
+	}
+}
+
+/// Binary representation of a bit string
+///
+/// `Binary(&bits)` implements [`Display`](fmt::Display) (with `0b`
+/// prefix in alternate mode), `Binary<B>` implements [`FromStr`]
+/// (accepting an optional `0b` prefix).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Binary<B>(pub B);
+
+impl<B: BitString> fmt::Display for Binary<&B> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		if f.alternate() {
+			f.write_str("0b")?;
+		}
+		write_binary(f, self.0)
+	}
+}
+
+impl<B: BitString> FromStr for Binary<B> {
+	type Err = ParseError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		Ok(Binary(parse_binary(s)?))
+	}
+}
+
+/// Hex representation of a bit string
+///
+/// `Hex(&bits)` implements [`Display`](fmt::Display) (with `0x` prefix
+/// in alternate mode), `Hex<B>` implements [`FromStr`] (accepting an
+/// optional `0x` prefix).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Hex<B>(pub B);
+
+impl<B: BitString> fmt::Display for Hex<&B> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		if f.alternate() {
+			f.write_str("0x")?;
+		}
+		write_hex(f, self.0)
+	}
+}
+
+impl<B: BitString> FromStr for Hex<B> {
+	type Err = ParseError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		Ok(Hex(parse_hex(s)?))
+	}
+}
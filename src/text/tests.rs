@@ -0,0 +1,95 @@
+use alloc::{
+	format,
+	string::String,
+};
+use core::{
+	fmt::Write,
+	net::Ipv4Addr,
+};
+
+use crate::{
+	text::{
+		parse_binary,
+		parse_hex,
+		parse_prefixed,
+		write_hex,
+		Binary,
+		Hex,
+		ParseError,
+	},
+	BitLengthString,
+	BitString,
+	ByteString,
+};
+
+type Prefix = BitLengthString<Ipv4Addr>;
+
+fn p(addr: [u8; 4], len: usize) -> Prefix {
+	Prefix::new(Ipv4Addr::from(addr), len)
+}
+
+#[test]
+fn test_format() {
+	let bits = p([0xb0, 0, 0, 0], 5);
+	assert_eq!(format!("{}", Binary(&bits)), "10110");
+	assert_eq!(format!("{:#}", Binary(&bits)), "0b10110");
+	assert_eq!(format!("{}", Hex(&bits)), "b0/5");
+	assert_eq!(format!("{:#}", Hex(&bits)), "0xb0/5");
+
+	let bits = p([10, 0, 0, 0], 8);
+	assert_eq!(format!("{}", Hex(&bits)), "0a");
+	assert_eq!(format!("{}", Binary(&bits)), "00001010");
+	assert_eq!(format!("{}", Hex(&p([0, 0, 0, 0], 0))), "");
+
+	let mut buf = String::new();
+	write_hex(&mut buf, &ByteString::from(&b"\xde\xad"[..])).unwrap();
+	let mut bits = ByteString::from(&b"\x80"[..]);
+	bits.clip(3);
+	write!(buf, " {}", Binary(&bits)).unwrap();
+	assert_eq!(buf, "dead 100");
+}
+
+#[test]
+fn test_parse() {
+	let bits = p([0xb0, 0, 0, 0], 5);
+	assert_eq!(parse_binary::<Prefix>("10110"), Ok(bits.clone()));
+	assert_eq!(parse_binary::<Prefix>("0b10110"), Ok(bits.clone()));
+	assert_eq!(parse_hex::<Prefix>("b0/5"), Ok(bits.clone()));
+	assert_eq!(parse_hex::<Prefix>("0xb/5"), Ok(bits.clone()));
+	assert_eq!(parse_hex::<Prefix>("B0/5"), Ok(bits.clone()));
+	assert_eq!(parse_prefixed::<Prefix>("0b10110"), Ok(bits.clone()));
+	assert_eq!(parse_prefixed::<Prefix>("0xb0/5"), Ok(bits.clone()));
+	assert_eq!("10110".parse::<Binary<Prefix>>(), Ok(Binary(bits.clone())));
+	assert_eq!("b0/5".parse::<Hex<Prefix>>(), Ok(Hex(bits)));
+	assert_eq!(parse_hex::<Prefix>("0a"), Ok(p([10, 0, 0, 0], 8)));
+	assert_eq!(parse_binary::<Prefix>(""), Ok(Prefix::null()));
+	assert_eq!(parse_hex::<Prefix>("/0"), Ok(Prefix::null()));
+	assert_eq!(
+		parse_hex::<ByteString>("dead"),
+		Ok(ByteString::from(&b"\xde\xad"[..]))
+	);
+
+	assert_eq!(
+		parse_binary::<Prefix>("10120"),
+		Err(ParseError::InvalidDigit(3))
+	);
+	assert_eq!(
+		parse_hex::<Prefix>("0xbg"),
+		Err(ParseError::InvalidDigit(3))
+	);
+	assert_eq!(parse_hex::<Prefix>("b4/5"), Err(ParseError::NonZeroPadding));
+	assert_eq!(parse_hex::<Prefix>("b/"), Err(ParseError::InvalidLength));
+	assert_eq!(parse_hex::<Prefix>("b/+5"), Err(ParseError::InvalidLength));
+	assert_eq!(
+		parse_prefixed::<Prefix>("10110"),
+		Err(ParseError::MissingPrefix)
+	);
+	assert_eq!(parse_hex::<Prefix>("000000000"), Err(ParseError::TooLong));
+	assert_eq!(parse_hex::<Prefix>("/33"), Err(ParseError::TooLong));
+	assert_eq!(parse_hex::<Prefix>("b/8"), Err(ParseError::InvalidLength));
+	// must not try to append all the missing bits
+	assert_eq!(
+		parse_hex::<ByteString>("/99999999999999"),
+		Err(ParseError::InvalidLength)
+	);
+}
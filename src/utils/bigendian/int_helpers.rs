@@ -25,6 +25,16 @@ macro_rules! impl_big_endian_for {
 				}
 			}
 
+			/// integer with the lowest `width` bits set
+			const fn mask_low(width: usize) -> $t {
+				assert!(width <= ELEMENT_BITS);
+				if width >= ELEMENT_BITS {
+					!0
+				} else {
+					(1 << width) - 1
+				}
+			}
+
 			/// increment from right; don't touch first `prefix` bits; returns
 			/// true on overflow
 			///
@@ -323,6 +333,567 @@ macro_rules! impl_big_endian_for {
 				0 == mask & (value ^ other)
 			}
 
+			/// Number of bits set to `true` (`1`).
+			pub const fn element_count_ones(value: $t) -> usize {
+				value.count_ones() as usize
+			}
+
+			/// Number of bits set to `true` (`1`).
+			pub fn slice_count_ones(slice: &[$t]) -> usize {
+				slice.iter().map(|&value| element_count_ones(value)).sum()
+			}
+
+			/// Index of the first bit set to `true` (`1`) in [ndx..].
+			///
+			/// Returns `None` if there is none (including if `ndx >=
+			/// ELEMENT_BITS`).
+			pub const fn element_find_next_set(value: $t, ndx: usize) -> Option<usize> {
+				if ndx >= ELEMENT_BITS {
+					return None;
+				}
+				let value = value & mask_suffix(ndx);
+				if value == 0 {
+					None
+				} else {
+					Some(value.leading_zeros() as usize)
+				}
+			}
+
+			/// Index of the first bit set to `true` (`1`) in [ndx..].
+			///
+			/// Returns `None` if there is none (including if `ndx >=
+			/// ELEMENT_BITS * slice.len()`).
+			pub fn slice_find_next_set(slice: &[$t], ndx: usize) -> Option<usize> {
+				let slice_ndx = ndx / ELEMENT_BITS;
+				if slice_ndx >= slice.len() {
+					return None;
+				}
+				if let Some(found) = element_find_next_set(slice[slice_ndx], ndx % ELEMENT_BITS) {
+					return Some(slice_ndx * ELEMENT_BITS + found);
+				}
+				let (i, &value) = slice[slice_ndx + 1..]
+					.iter()
+					.enumerate()
+					.find(|(_, &value)| value != 0)?;
+				Some((slice_ndx + 1 + i) * ELEMENT_BITS + value.leading_zeros() as usize)
+			}
+
+			/// Index of the first bit set to `false` (`0`) in [ndx..].
+			///
+			/// Returns `None` if there is none (including if `ndx >=
+			/// ELEMENT_BITS`).
+			pub const fn element_find_next_clear(value: $t, ndx: usize) -> Option<usize> {
+				element_find_next_set(!value, ndx)
+			}
+
+			/// Index of the first bit set to `false` (`0`) in [ndx..].
+			///
+			/// Returns `None` if there is none (including if `ndx >=
+			/// ELEMENT_BITS * slice.len()`).
+			pub fn slice_find_next_clear(slice: &[$t], ndx: usize) -> Option<usize> {
+				let slice_ndx = ndx / ELEMENT_BITS;
+				if slice_ndx >= slice.len() {
+					return None;
+				}
+				if let Some(found) = element_find_next_clear(slice[slice_ndx], ndx % ELEMENT_BITS) {
+					return Some(slice_ndx * ELEMENT_BITS + found);
+				}
+				let (i, &value) = slice[slice_ndx + 1..]
+					.iter()
+					.enumerate()
+					.find(|(_, &value)| value != !0)?;
+				Some((slice_ndx + 1 + i) * ELEMENT_BITS + value.leading_ones() as usize)
+			}
+
+			/// Number of consecutive bits set to `false` (`0`) starting at
+			/// `ndx`.
+			///
+			/// Returns 0 if `ndx >= ELEMENT_BITS`.
+			pub const fn element_leading_zeros_from(value: $t, ndx: usize) -> usize {
+				if ndx >= ELEMENT_BITS {
+					return 0;
+				}
+				match element_find_next_set(value, ndx) {
+					Some(found) => found - ndx,
+					None => ELEMENT_BITS - ndx,
+				}
+			}
+
+			/// Number of consecutive bits set to `false` (`0`) starting at
+			/// `ndx`.
+			///
+			/// Returns 0 if `ndx >= ELEMENT_BITS * slice.len()`.
+			pub fn slice_leading_zeros_from(slice: &[$t], ndx: usize) -> usize {
+				let bits = ELEMENT_BITS * slice.len();
+				if ndx >= bits {
+					return 0;
+				}
+				slice_find_next_set(slice, ndx).unwrap_or(bits) - ndx
+			}
+
+			/// Number of consecutive bits set to `true` (`1`) starting at
+			/// `ndx`.
+			///
+			/// Returns 0 if `ndx >= ELEMENT_BITS`.
+			pub const fn element_leading_ones_from(value: $t, ndx: usize) -> usize {
+				element_leading_zeros_from(!value, ndx)
+			}
+
+			/// Number of consecutive bits set to `true` (`1`) starting at
+			/// `ndx`.
+			///
+			/// Returns 0 if `ndx >= ELEMENT_BITS * slice.len()`.
+			pub fn slice_leading_ones_from(slice: &[$t], ndx: usize) -> usize {
+				let bits = ELEMENT_BITS * slice.len();
+				if ndx >= bits {
+					return 0;
+				}
+				slice_find_next_clear(slice, ndx).unwrap_or(bits) - ndx
+			}
+
+			/// Get `width` bits starting at bit `offset` as integer (bit
+			/// `offset + width - 1` is the least significant bit).
+			///
+			/// # Panics
+			///
+			/// Panics if `offset + width > ELEMENT_BITS`.
+			pub const fn element_extract(value: $t, offset: usize, width: usize) -> $t {
+				assert!(offset + width <= ELEMENT_BITS);
+				if width == 0 {
+					return 0;
+				}
+				(value >> (ELEMENT_BITS - offset - width)) & mask_low(width)
+			}
+
+			/// Get `width` bits starting at bit `offset` as integer (bit
+			/// `offset + width - 1` is the least significant bit).
+			///
+			/// # Panics
+			///
+			/// Panics if `width > 128` or `offset + width > ELEMENT_BITS *
+			/// slice.len()`.
+			pub fn slice_extract(slice: &[$t], offset: usize, width: usize) -> u128 {
+				assert!(width <= 128);
+				let end = offset + width;
+				assert!(end <= ELEMENT_BITS * slice.len());
+				let mut result: u128 = 0;
+				let mut ndx = offset;
+				while ndx < end {
+					let element_ndx = ndx % ELEMENT_BITS;
+					let chunk = min(ELEMENT_BITS - element_ndx, end - ndx);
+					let bits = element_extract(slice[ndx / ELEMENT_BITS], element_ndx, chunk);
+					// `chunk` is only 128 for the first chunk (`result == 0`)
+					result = result.checked_shl(chunk as u32).unwrap_or(0) | bits as u128;
+					ndx += chunk;
+				}
+				result
+			}
+
+			/// Replace `width` bits starting at bit `offset` with `field`.
+			///
+			/// # Panics
+			///
+			/// Panics if `offset + width > ELEMENT_BITS` or if `field`
+			/// doesn't fit into `width` bits.
+			pub const fn make_element_insert(
+				value: $t,
+				offset: usize,
+				width: usize,
+				field: $t,
+			) -> $t {
+				assert!(offset + width <= ELEMENT_BITS);
+				assert!(0 == field & !mask_low(width));
+				if width == 0 {
+					return value;
+				}
+				let shift = ELEMENT_BITS - offset - width;
+				(value & !(mask_low(width) << shift)) | (field << shift)
+			}
+
+			/// Replace `width` bits starting at bit `offset` with `field`.
+			///
+			/// # Panics
+			///
+			/// Panics if `offset + width > ELEMENT_BITS` or if `field`
+			/// doesn't fit into `width` bits.
+			pub fn element_insert(value: &mut $t, offset: usize, width: usize, field: $t) {
+				*value = make_element_insert(*value, offset, width, field);
+			}
+
+			/// Replace `width` bits starting at bit `offset` with `field`.
+			///
+			/// # Panics
+			///
+			/// Panics if `width > 128`, `offset + width > ELEMENT_BITS *
+			/// slice.len()` or if `field` doesn't fit into `width` bits.
+			pub fn slice_insert(slice: &mut [$t], offset: usize, width: usize, field: u128) {
+				assert!(width <= 128);
+				assert!(width == 128 || 0 == field >> width);
+				let end = offset + width;
+				assert!(end <= ELEMENT_BITS * slice.len());
+				let mut ndx = offset;
+				while ndx < end {
+					let element_ndx = ndx % ELEMENT_BITS;
+					let chunk = min(ELEMENT_BITS - element_ndx, end - ndx);
+					// `chunk` bits of `field` starting at `ndx - offset`
+					let shifted = field >> (end - ndx - chunk);
+					let bits = if chunk >= 128 {
+						shifted
+					} else {
+						shifted & ((1 << chunk) - 1)
+					};
+					element_insert(
+						&mut slice[ndx / ELEMENT_BITS],
+						element_ndx,
+						chunk,
+						bits as $t,
+					);
+					ndx += chunk;
+				}
+			}
+
+			/// Shift bits [prefix..] by `count` bits towards the start (like
+			/// `<<` on integers); the first `prefix` bits are not modified,
+			/// bits at the end are filled with `false` (`0`).
+			///
+			/// Doesn't do anything if `prefix >= ELEMENT_BITS`.
+			pub const fn make_element_shl_from(value: $t, prefix: usize, count: usize) -> $t {
+				if prefix >= ELEMENT_BITS {
+					return value;
+				}
+				let host_mask = mask_suffix(prefix);
+				let shifted = if count >= ELEMENT_BITS {
+					0
+				} else {
+					value << count
+				};
+				(value & !host_mask) | (shifted & host_mask)
+			}
+
+			/// Shift bits [prefix..] by `count` bits towards the start (like
+			/// `<<` on integers); the first `prefix` bits are not modified,
+			/// bits at the end are filled with `false` (`0`).
+			///
+			/// Doesn't do anything if `prefix >= ELEMENT_BITS`.
+			pub fn element_shl_from(value: &mut $t, prefix: usize, count: usize) {
+				*value = make_element_shl_from(*value, prefix, count);
+			}
+
+			/// Shift bits [prefix..] by `count` bits towards the end (like
+			/// `>>` on unsigned integers); the first `prefix` bits are not
+			/// modified, bits after the prefix are filled with `false` (`0`).
+			///
+			/// Doesn't do anything if `prefix >= ELEMENT_BITS`.
+			pub const fn make_element_shr_from(value: $t, prefix: usize, count: usize) -> $t {
+				if prefix >= ELEMENT_BITS {
+					return value;
+				}
+				let host_mask = mask_suffix(prefix);
+				let host = value & host_mask;
+				let shifted = if count >= ELEMENT_BITS {
+					0
+				} else {
+					host >> count
+				};
+				(value & !host_mask) | shifted
+			}
+
+			/// Shift bits [prefix..] by `count` bits towards the end (like
+			/// `>>` on unsigned integers); the first `prefix` bits are not
+			/// modified, bits after the prefix are filled with `false` (`0`).
+			///
+			/// Doesn't do anything if `prefix >= ELEMENT_BITS`.
+			pub fn element_shr_from(value: &mut $t, prefix: usize, count: usize) {
+				*value = make_element_shr_from(*value, prefix, count);
+			}
+
+			/// Rotate bits [start..end] by `count` bits towards the start;
+			/// bits shifted out at `start` come back in before `end`.
+			///
+			/// # Panics
+			///
+			/// Panics if `start > end` or `end > ELEMENT_BITS`.
+			pub const fn make_element_rotate_left(
+				value: $t,
+				start: usize,
+				end: usize,
+				count: usize,
+			) -> $t {
+				assert!(start <= end && end <= ELEMENT_BITS);
+				let len = end - start;
+				if len == 0 || count % len == 0 {
+					return value;
+				}
+				let count = count % len;
+				let field = element_extract(value, start, len);
+				let rotated = ((field << count) | (field >> (len - count))) & mask_low(len);
+				make_element_insert(value, start, len, rotated)
+			}
+
+			/// Rotate bits [start..end] by `count` bits towards the start;
+			/// bits shifted out at `start` come back in before `end`.
+			///
+			/// # Panics
+			///
+			/// Panics if `start > end` or `end > ELEMENT_BITS`.
+			pub fn element_rotate_left(value: &mut $t, start: usize, end: usize, count: usize) {
+				*value = make_element_rotate_left(*value, start, end, count);
+			}
+
+			/// Rotate bits [start..end] by `count` bits towards the end;
+			/// bits shifted out at `end` come back in at `start`.
+			///
+			/// # Panics
+			///
+			/// Panics if `start > end` or `end > ELEMENT_BITS`.
+			pub const fn make_element_rotate_right(
+				value: $t,
+				start: usize,
+				end: usize,
+				count: usize,
+			) -> $t {
+				assert!(start <= end && end <= ELEMENT_BITS);
+				let len = end - start;
+				if len == 0 {
+					return value;
+				}
+				make_element_rotate_left(value, start, end, len - count % len)
+			}
+
+			/// Rotate bits [start..end] by `count` bits towards the end;
+			/// bits shifted out at `end` come back in at `start`.
+			///
+			/// # Panics
+			///
+			/// Panics if `start > end` or `end > ELEMENT_BITS`.
+			pub fn element_rotate_right(value: &mut $t, start: usize, end: usize, count: usize) {
+				*value = make_element_rotate_right(*value, start, end, count);
+			}
+
+			/// shift bits [start..end] towards the start, filling with `false`
+			fn slice_shl_range(slice: &mut [$t], start: usize, end: usize, count: usize) {
+				let mut ndx = start;
+				while ndx < end {
+					// write [ndx..ndx + width] from [src..src + available];
+					// reading ahead of the written bits
+					let width = min(min(ELEMENT_BITS, 128), end - ndx);
+					let src = ndx.saturating_add(count);
+					let value = if src >= end {
+						0
+					} else {
+						let available = min(width, end - src);
+						slice_extract(slice, src, available) << (width - available)
+					};
+					slice_insert(slice, ndx, width, value);
+					ndx += width;
+				}
+			}
+
+			/// shift bits [start..end] towards the end, filling with `false`
+			fn slice_shr_range(slice: &mut [$t], start: usize, end: usize, count: usize) {
+				let mut ndx = end;
+				while ndx > start {
+					// write [ndx - width..ndx] from [src..ndx - count]; reading
+					// behind the written bits
+					let width = min(min(ELEMENT_BITS, 128), ndx - start);
+					let value = if ndx - start <= count {
+						0
+					} else {
+						let src = (ndx - width).saturating_sub(count).max(start);
+						slice_extract(slice, src, ndx - count - src)
+					};
+					slice_insert(slice, ndx - width, width, value);
+					ndx -= width;
+				}
+			}
+
+			/// Shift bits [prefix..] by `count` bits towards the start (like
+			/// `<<` on integers); the first `prefix` bits are not modified,
+			/// bits at the end are filled with `false` (`0`).
+			///
+			/// Doesn't do anything if `prefix >= ELEMENT_BITS * slice.len()`.
+			pub fn slice_shl_from(slice: &mut [$t], prefix: usize, count: usize) {
+				let bits = ELEMENT_BITS * slice.len();
+				if prefix < bits && count > 0 {
+					slice_shl_range(slice, prefix, bits, count);
+				}
+			}
+
+			/// Shift bits [prefix..] by `count` bits towards the end (like
+			/// `>>` on unsigned integers); the first `prefix` bits are not
+			/// modified, bits after the prefix are filled with `false` (`0`).
+			///
+			/// Doesn't do anything if `prefix >= ELEMENT_BITS * slice.len()`.
+			pub fn slice_shr_from(slice: &mut [$t], prefix: usize, count: usize) {
+				let bits = ELEMENT_BITS * slice.len();
+				if prefix < bits && count > 0 {
+					slice_shr_range(slice, prefix, bits, count);
+				}
+			}
+
+			/// reverse the order of bits [start..end]
+			fn slice_reverse_range(slice: &mut [$t], start: usize, end: usize) {
+				// swap up to 128 bits at a time from both ends
+				let (mut low, mut high) = (start, end);
+				while high - low > 1 {
+					let step = min((high - low) / 2, 128);
+					let head = slice_extract(slice, low, step);
+					let tail = slice_extract(slice, high - step, step);
+					slice_insert(slice, low, step, tail.reverse_bits() >> (128 - step));
+					slice_insert(
+						slice,
+						high - step,
+						step,
+						head.reverse_bits() >> (128 - step),
+					);
+					low += step;
+					high -= step;
+				}
+			}
+
+			/// Rotate bits [start..end] by `count` bits towards the start;
+			/// bits shifted out at `start` come back in before `end`.
+			///
+			/// # Panics
+			///
+			/// Panics if `start > end` or `end > ELEMENT_BITS * slice.len()`.
+			pub fn slice_rotate_left(slice: &mut [$t], start: usize, end: usize, count: usize) {
+				assert!(start <= end && end <= ELEMENT_BITS * slice.len());
+				let len = end - start;
+				if len == 0 || count % len == 0 {
+					return;
+				}
+				let mid = start + count % len;
+				slice_reverse_range(slice, start, mid);
+				slice_reverse_range(slice, mid, end);
+				slice_reverse_range(slice, start, end);
+			}
+
+			/// Rotate bits [start..end] by `count` bits towards the end;
+			/// bits shifted out at `end` come back in at `start`.
+			///
+			/// # Panics
+			///
+			/// Panics if `start > end` or `end > ELEMENT_BITS * slice.len()`.
+			pub fn slice_rotate_right(slice: &mut [$t], start: usize, end: usize, count: usize) {
+				assert!(start <= end && end <= ELEMENT_BITS * slice.len());
+				let len = end - start;
+				if len == 0 {
+					return;
+				}
+				slice_rotate_left(slice, start, end, len - count % len);
+			}
+
+			/// Add `n` to the bits [prefix..] (treated as integer); don't
+			/// touch first `prefix` bits.  Wraps around and returns true on
+			/// overflow.
+			///
+			/// # Panics
+			///
+			/// Panics if `prefix > ELEMENT_BITS`.
+			pub const fn make_element_add(value: $t, prefix: usize, n: u128) -> ($t, bool) {
+				assert!(prefix <= ELEMENT_BITS);
+				let host_bits = ELEMENT_BITS - prefix;
+				let host_mask = mask_suffix(prefix);
+				let (n, n_overflow) = if host_bits >= 128 {
+					(n, false)
+				} else {
+					(n & ((1 << host_bits) - 1), 0 != n >> host_bits)
+				};
+				let (sum, carry) = ((value & host_mask) as u128).overflowing_add(n);
+				let overflow = n_overflow || carry || 0 != sum & !(host_mask as u128);
+				((value & !host_mask) | (sum as $t & host_mask), overflow)
+			}
+
+			/// Add `n` to the bits [prefix..] (treated as integer); don't
+			/// touch first `prefix` bits.  Wraps around and returns true on
+			/// overflow.
+			///
+			/// # Panics
+			///
+			/// Panics if `prefix > ELEMENT_BITS`.
+			pub fn element_add(value: &mut $t, prefix: usize, n: u128) -> bool {
+				let overflow;
+				(*value, overflow) = make_element_add(*value, prefix, n);
+				overflow
+			}
+
+			/// Subtract `n` from the bits [prefix..] (treated as integer);
+			/// don't touch first `prefix` bits.  Wraps around and returns
+			/// true on overflow.
+			///
+			/// # Panics
+			///
+			/// Panics if `prefix > ELEMENT_BITS`.
+			pub const fn make_element_sub(value: $t, prefix: usize, n: u128) -> ($t, bool) {
+				assert!(prefix <= ELEMENT_BITS);
+				let host_bits = ELEMENT_BITS - prefix;
+				let host_mask = mask_suffix(prefix);
+				let (n, n_overflow) = if host_bits >= 128 {
+					(n, false)
+				} else {
+					(n & ((1 << host_bits) - 1), 0 != n >> host_bits)
+				};
+				let (diff, borrow) = ((value & host_mask) as u128).overflowing_sub(n);
+				(
+					(value & !host_mask) | (diff as $t & host_mask),
+					n_overflow || borrow,
+				)
+			}
+
+			/// Subtract `n` from the bits [prefix..] (treated as integer);
+			/// don't touch first `prefix` bits.  Wraps around and returns
+			/// true on overflow.
+			///
+			/// # Panics
+			///
+			/// Panics if `prefix > ELEMENT_BITS`.
+			pub fn element_sub(value: &mut $t, prefix: usize, n: u128) -> bool {
+				let overflow;
+				(*value, overflow) = make_element_sub(*value, prefix, n);
+				overflow
+			}
+
+			/// Absolute difference between the bits [prefix..] of `value`
+			/// and `other` (treated as integers); the first `prefix` bits
+			/// are ignored.
+			///
+			/// # Panics
+			///
+			/// Panics if `prefix > ELEMENT_BITS`.
+			pub const fn element_distance(value: $t, prefix: usize, other: $t) -> $t {
+				let host_mask = mask_suffix(prefix);
+				(value & host_mask).abs_diff(other & host_mask)
+			}
+
+			/// Bits from [prefix..] as integer, i.e. the offset of `value`
+			/// within its prefix of length `prefix`.
+			///
+			/// # Panics
+			///
+			/// Panics if `prefix > ELEMENT_BITS`.
+			pub const fn element_offset_in(value: $t, prefix: usize) -> $t {
+				value & mask_suffix(prefix)
+			}
+
+			/// Replace bits from [prefix..] with `offset`; returns `None` if
+			/// `offset` doesn't fit into `ELEMENT_BITS - prefix` bits.
+			///
+			/// # Panics
+			///
+			/// Panics if `prefix > ELEMENT_BITS`.
+			pub const fn make_element_with_offset(
+				value: $t,
+				prefix: usize,
+				offset: $t,
+			) -> Option<$t> {
+				let host_mask = mask_suffix(prefix);
+				if 0 != offset & !host_mask {
+					return None;
+				}
+				Some((value & !host_mask) | offset)
+			}
+
 			/// check whether another bit string `other` shares the first
 			/// `prefix` bits with `slice`
 			///
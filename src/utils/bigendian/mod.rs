@@ -5,10 +5,11 @@ pub use self::int_helpers::{
 	u128,
 	u16,
 	u32,
+	u64,
 	u8,
 };
 
-#[cfg(any(feature = "bigendian", feature = "net"))]
+#[cfg(any(feature = "bigendian", feature = "fixed"))]
 pub(crate) mod int_helpers;
 
 #[cfg(feature = "bigendian")]
@@ -1,4 +1,7 @@
-use super::int_helpers::u8;
+use super::int_helpers::{
+	u128,
+	u8,
+};
 
 fn u8_slice_inc<S: AsMut<[u8]>>(mut slice: S, prefix: usize) -> (bool, S) {
 	let overflow = u8::slice_inc(slice.as_mut(), prefix);
@@ -25,6 +28,36 @@ fn u8_slice_set_true_from<S: AsMut<[u8]>>(mut slice: S, ndx: usize) -> S {
 	slice
 }
 
+fn u8_slice_shl_from<S: AsMut<[u8]>>(mut slice: S, prefix: usize, count: usize) -> S {
+	u8::slice_shl_from(slice.as_mut(), prefix, count);
+	slice
+}
+
+fn u8_slice_shr_from<S: AsMut<[u8]>>(mut slice: S, prefix: usize, count: usize) -> S {
+	u8::slice_shr_from(slice.as_mut(), prefix, count);
+	slice
+}
+
+fn u8_slice_rotate_left<S: AsMut<[u8]>>(mut slice: S, start: usize, end: usize, count: usize) -> S {
+	u8::slice_rotate_left(slice.as_mut(), start, end, count);
+	slice
+}
+
+fn u8_slice_rotate_right<S: AsMut<[u8]>>(
+	mut slice: S,
+	start: usize,
+	end: usize,
+	count: usize,
+) -> S {
+	u8::slice_rotate_right(slice.as_mut(), start, end, count);
+	slice
+}
+
+fn u8_slice_insert<S: AsMut<[u8]>>(mut slice: S, offset: usize, width: usize, value: u128) -> S {
+	u8::slice_insert(slice.as_mut(), offset, width, value);
+	slice
+}
+
 #[test]
 fn test_u8_element_inc() {
 	assert_eq!(u8::make_element_inc(0b0000_0000, 0), (0b0000_0001, false));
@@ -206,6 +239,614 @@ fn test_u8_element_contains() {
 	assert!(!u8::element_contains(0b1111_1111, 8, 0b0111_1111));
 }
 
+#[test]
+fn test_u8_element_offset_in() {
+	assert_eq!(u8::element_offset_in(0b1010_0110, 0), 0b1010_0110);
+	assert_eq!(u8::element_offset_in(0b1010_0110, 3), 0b0000_0110);
+	assert_eq!(u8::element_offset_in(0b1010_0110, 7), 0);
+	assert_eq!(u8::element_offset_in(0b1010_0111, 7), 1);
+	assert_eq!(u8::element_offset_in(0b1111_1111, 8), 0);
+}
+
+#[test]
+fn test_u8_make_element_with_offset() {
+	assert_eq!(
+		u8::make_element_with_offset(0b1010_0110, 3, 0b1_1111),
+		Some(0b1011_1111)
+	);
+	assert_eq!(
+		u8::make_element_with_offset(0b1010_0110, 3, 0),
+		Some(0b1010_0000)
+	);
+	assert_eq!(
+		u8::make_element_with_offset(0b1010_0110, 3, 0b10_0000),
+		None
+	);
+	assert_eq!(u8::make_element_with_offset(0, 0, 0xff), Some(0xff));
+	assert_eq!(u8::make_element_with_offset(0xff, 8, 0), Some(0xff));
+	assert_eq!(u8::make_element_with_offset(0xff, 8, 1), None);
+}
+
+#[test]
+fn test_u8_element_extract() {
+	assert_eq!(u8::element_extract(0b1010_0110, 0, 0), 0);
+	assert_eq!(u8::element_extract(0b1010_0110, 8, 0), 0);
+	assert_eq!(u8::element_extract(0b1010_0110, 0, 8), 0b1010_0110);
+	assert_eq!(u8::element_extract(0b1010_0110, 0, 1), 0b1);
+	assert_eq!(u8::element_extract(0b1010_0110, 1, 1), 0b0);
+	assert_eq!(u8::element_extract(0b1010_0110, 0, 3), 0b101);
+	assert_eq!(u8::element_extract(0b1010_0110, 2, 4), 0b1001);
+	assert_eq!(u8::element_extract(0b1010_0110, 5, 3), 0b110);
+	assert_eq!(u8::element_extract(0b1010_0110, 7, 1), 0b0);
+}
+
+#[test]
+#[should_panic]
+fn test_u8_element_extract_out_of_range() {
+	u8::element_extract(0, 5, 4);
+}
+
+#[test]
+fn test_u8_make_element_insert() {
+	assert_eq!(u8::make_element_insert(0b1010_0110, 0, 0, 0), 0b1010_0110);
+	assert_eq!(u8::make_element_insert(0b1010_0110, 8, 0, 0), 0b1010_0110);
+	assert_eq!(
+		u8::make_element_insert(0b1010_0110, 0, 8, 0b0101_1001),
+		0b0101_1001
+	);
+	assert_eq!(u8::make_element_insert(0b1010_0110, 0, 1, 0), 0b0010_0110);
+	assert_eq!(u8::make_element_insert(0b1010_0110, 1, 1, 1), 0b1110_0110);
+	assert_eq!(
+		u8::make_element_insert(0b1010_0110, 2, 4, 0b0110),
+		0b1001_1010
+	);
+	assert_eq!(
+		u8::make_element_insert(0b1010_0110, 5, 3, 0b001),
+		0b1010_0001
+	);
+	assert_eq!(u8::make_element_insert(0b0000_0000, 7, 1, 1), 0b0000_0001);
+}
+
+#[test]
+#[should_panic]
+fn test_u8_make_element_insert_too_large() {
+	u8::make_element_insert(0, 2, 4, 0b1_0000);
+}
+
+#[test]
+fn test_u8_slice_extract() {
+	assert_eq!(u8::slice_extract(&[0b1010_0110, 0b0101_1001], 0, 0), 0);
+	assert_eq!(u8::slice_extract(&[0b1010_0110, 0b0101_1001], 16, 0), 0);
+	assert_eq!(
+		u8::slice_extract(&[0b1010_0110, 0b0101_1001], 0, 16),
+		0b1010_0110_0101_1001
+	);
+	assert_eq!(u8::slice_extract(&[0b1010_0110, 0b0101_1001], 6, 4), 0b1001);
+	assert_eq!(u8::slice_extract(&[0b1010_0110, 0b0101_1001], 7, 2), 0b00);
+	assert_eq!(
+		u8::slice_extract(&[0b1010_0110, 0b0101_1001], 8, 8),
+		0b0101_1001
+	);
+	assert_eq!(
+		u8::slice_extract(&[0b1010_0110, 0b0101_1001], 12, 4),
+		0b1001
+	);
+	assert_eq!(
+		u8::slice_extract(&[0, 0, 0, 0, 0, 0, 0b0001_1111, 0b1111_1111], 51, 13),
+		0x1fff
+	);
+	assert_eq!(
+		u8::slice_extract(&[0, 0, 0, 0, 0, 0, 0b0010_1010, 0b1010_1011], 51, 13),
+		0b0_1010_1010_1011
+	);
+	assert_eq!(u8::slice_extract(&[0xff; 17], 4, 128), u128::MAX);
+}
+
+#[test]
+#[should_panic]
+fn test_u8_slice_extract_out_of_range() {
+	u8::slice_extract(&[0, 0], 9, 8);
+}
+
+#[test]
+fn test_u8_slice_insert() {
+	assert_eq!(
+		u8_slice_insert([0b1010_0110, 0b0101_1001], 0, 0, 0),
+		[0b1010_0110, 0b0101_1001]
+	);
+	assert_eq!(
+		u8_slice_insert([0b1010_0110, 0b0101_1001], 0, 16, 0x1234),
+		[0x12, 0x34]
+	);
+	assert_eq!(
+		u8_slice_insert([0b1010_0110, 0b0101_1001], 6, 4, 0b0110),
+		[0b1010_0101, 0b1001_1001]
+	);
+	assert_eq!(
+		u8_slice_insert([0b1010_0110, 0b0101_1001], 8, 8, 0),
+		[0b1010_0110, 0]
+	);
+	assert_eq!(
+		u8_slice_insert([0; 8], 51, 13, 0x1fff),
+		[0, 0, 0, 0, 0, 0, 0b0001_1111, 0xff]
+	);
+	assert_eq!(u8_slice_insert([0; 17], 4, 128, u128::MAX), {
+		let mut expected = [0xff; 17];
+		expected[0] = 0x0f;
+		expected[16] = 0xf0;
+		expected
+	});
+}
+
+#[test]
+#[should_panic]
+fn test_u8_slice_insert_too_large() {
+	u8_slice_insert([0, 0], 4, 8, 0x100);
+}
+
+#[test]
+fn test_u8_element_count_ones() {
+	assert_eq!(u8::element_count_ones(0b0000_0000), 0);
+	assert_eq!(u8::element_count_ones(0b1000_0001), 2);
+	assert_eq!(u8::element_count_ones(0b1111_1111), 8);
+	assert_eq!(u8::slice_count_ones(&[]), 0);
+	assert_eq!(u8::slice_count_ones(&[0b1010_0110, 0b1111_1111]), 12);
+}
+
+#[test]
+fn test_u8_element_find_next() {
+	assert_eq!(u8::element_find_next_set(0b0000_0000, 0), None);
+	assert_eq!(u8::element_find_next_set(0b0010_0100, 0), Some(2));
+	assert_eq!(u8::element_find_next_set(0b0010_0100, 2), Some(2));
+	assert_eq!(u8::element_find_next_set(0b0010_0100, 3), Some(5));
+	assert_eq!(u8::element_find_next_set(0b0010_0100, 6), None);
+	assert_eq!(u8::element_find_next_set(0b1111_1111, 8), None);
+	assert_eq!(u8::element_find_next_clear(0b1111_1111, 0), None);
+	assert_eq!(u8::element_find_next_clear(0b1101_1011, 0), Some(2));
+	assert_eq!(u8::element_find_next_clear(0b1101_1011, 3), Some(5));
+	assert_eq!(u8::element_find_next_clear(0b1101_1010, 6), Some(7));
+	assert_eq!(u8::element_find_next_clear(0b0000_0000, 8), None);
+}
+
+#[test]
+fn test_u8_element_leading_from() {
+	assert_eq!(u8::element_leading_zeros_from(0b0000_0000, 0), 8);
+	assert_eq!(u8::element_leading_zeros_from(0b0010_0100, 0), 2);
+	assert_eq!(u8::element_leading_zeros_from(0b0010_0100, 2), 0);
+	assert_eq!(u8::element_leading_zeros_from(0b0010_0100, 3), 2);
+	assert_eq!(u8::element_leading_zeros_from(0b0010_0100, 6), 2);
+	assert_eq!(u8::element_leading_zeros_from(0b0000_0000, 8), 0);
+	assert_eq!(u8::element_leading_ones_from(0b1111_1111, 0), 8);
+	assert_eq!(u8::element_leading_ones_from(0b1101_1011, 0), 2);
+	assert_eq!(u8::element_leading_ones_from(0b1101_1011, 3), 2);
+	assert_eq!(u8::element_leading_ones_from(0b1101_1011, 6), 2);
+	assert_eq!(u8::element_leading_ones_from(0b1111_1111, 8), 0);
+}
+
+#[test]
+fn test_u8_slice_find_next() {
+	assert_eq!(u8::slice_find_next_set(&[], 0), None);
+	assert_eq!(u8::slice_find_next_set(&[0, 0], 0), None);
+	assert_eq!(u8::slice_find_next_set(&[0, 0b0001_0000], 0), Some(11));
+	assert_eq!(
+		u8::slice_find_next_set(&[0b1000_0000, 0b0001_0000], 1),
+		Some(11)
+	);
+	assert_eq!(u8::slice_find_next_set(&[0, 0b0001_0000], 12), None);
+	assert_eq!(u8::slice_find_next_set(&[0, 0, 0, 1], 5), Some(31));
+	assert_eq!(u8::slice_find_next_set(&[0xff, 0xff], 16), None);
+	assert_eq!(u8::slice_find_next_clear(&[0xff, 0xff], 0), None);
+	assert_eq!(u8::slice_find_next_clear(&[0xff, 0b1110_1111], 0), Some(11));
+	assert_eq!(u8::slice_find_next_clear(&[0x7f, 0b1110_1111], 1), Some(11));
+	assert_eq!(
+		u8::slice_find_next_clear(&[0xff, 0xff, 0xff, 0xfe], 5),
+		Some(31)
+	);
+	assert_eq!(u8::slice_find_next_clear(&[0, 0], 16), None);
+}
+
+#[test]
+fn test_u8_slice_leading_from() {
+	assert_eq!(u8::slice_leading_zeros_from(&[0, 0], 0), 16);
+	assert_eq!(u8::slice_leading_zeros_from(&[0, 0], 3), 13);
+	assert_eq!(u8::slice_leading_zeros_from(&[0, 0b0001_0000], 1), 10);
+	assert_eq!(u8::slice_leading_zeros_from(&[0, 0], 16), 0);
+	assert_eq!(u8::slice_leading_ones_from(&[0xff, 0xff], 0), 16);
+	assert_eq!(u8::slice_leading_ones_from(&[0xff, 0b1110_1111], 4), 7);
+	assert_eq!(u8::slice_leading_ones_from(&[0xff, 0xff], 16), 0);
+}
+
+macro_rules! test_search_exhaustive {
+	($name:ident, $mod:ident) => {
+		#[test]
+		fn $name() {
+			use super::int_helpers::$mod as helpers;
+
+			const ELEMENTS: usize = 256 / helpers::ELEMENT_BITS;
+			let mut slice = [0; ELEMENTS];
+			// runs of different lengths of `true` and `false` bits
+			let mut ndx = 0;
+			for run in 1.. {
+				if ndx + run >= 256 {
+					break;
+				}
+				helpers::slice_set_true_from(&mut slice, ndx);
+				helpers::slice_set_false_from(&mut slice, ndx + run);
+				ndx += run + run % 7;
+			}
+			let bits: [bool; 256] = core::array::from_fn(|ndx| helpers::slice_get(&slice, ndx));
+			assert_eq!(
+				helpers::slice_count_ones(&slice),
+				bits.iter().filter(|&&bit| bit).count()
+			);
+			for ndx in 0..=256 {
+				let next_set = (ndx..256).find(|&i| bits[i]);
+				let next_clear = (ndx..256).find(|&i| !bits[i]);
+				assert_eq!(helpers::slice_find_next_set(&slice, ndx), next_set, "{ndx}");
+				assert_eq!(
+					helpers::slice_find_next_clear(&slice, ndx),
+					next_clear,
+					"{ndx}"
+				);
+				assert_eq!(
+					helpers::slice_leading_zeros_from(&slice, ndx),
+					next_set.unwrap_or(256.max(ndx)) - ndx
+				);
+				assert_eq!(
+					helpers::slice_leading_ones_from(&slice, ndx),
+					next_clear.unwrap_or(256.max(ndx)) - ndx
+				);
+				let element_ndx = ndx % helpers::ELEMENT_BITS;
+				let element = slice[(ndx / helpers::ELEMENT_BITS).min(ELEMENTS - 1)];
+				let element_bits = helpers::ELEMENT_BITS;
+				let element_next_set =
+					(element_ndx..element_bits).find(|&i| helpers::element_get(element, i));
+				assert_eq!(
+					helpers::element_find_next_set(element, element_ndx),
+					element_next_set
+				);
+				assert_eq!(
+					helpers::element_leading_zeros_from(element, element_ndx),
+					element_next_set.unwrap_or(element_bits) - element_ndx
+				);
+			}
+		}
+	};
+}
+
+test_search_exhaustive!(test_u8_search_exhaustive, u8);
+test_search_exhaustive!(test_u16_search_exhaustive, u16);
+test_search_exhaustive!(test_u32_search_exhaustive, u32);
+test_search_exhaustive!(test_u64_search_exhaustive, u64);
+test_search_exhaustive!(test_u128_search_exhaustive, u128);
+
+#[test]
+fn test_u8_make_element_shift_from() {
+	assert_eq!(u8::make_element_shl_from(0b1010_0110, 0, 0), 0b1010_0110);
+	assert_eq!(u8::make_element_shl_from(0b1010_0110, 0, 1), 0b0100_1100);
+	assert_eq!(u8::make_element_shl_from(0b1010_0110, 0, 7), 0b0000_0000);
+	assert_eq!(u8::make_element_shl_from(0b1010_0111, 0, 7), 0b1000_0000);
+	assert_eq!(u8::make_element_shl_from(0b1010_0111, 0, 8), 0b0000_0000);
+	assert_eq!(u8::make_element_shl_from(0b1010_0110, 3, 2), 0b1011_1000);
+	assert_eq!(u8::make_element_shl_from(0b1010_0110, 8, 2), 0b1010_0110);
+	assert_eq!(u8::make_element_shr_from(0b1010_0110, 0, 0), 0b1010_0110);
+	assert_eq!(u8::make_element_shr_from(0b1010_0110, 0, 1), 0b0101_0011);
+	assert_eq!(u8::make_element_shr_from(0b1010_0110, 0, 7), 0b0000_0001);
+	assert_eq!(u8::make_element_shr_from(0b1010_0110, 0, 8), 0b0000_0000);
+	assert_eq!(u8::make_element_shr_from(0b1010_0110, 3, 2), 0b1010_0001);
+	assert_eq!(u8::make_element_shr_from(0b1011_0110, 3, 1), 0b1010_1011);
+	assert_eq!(u8::make_element_shr_from(0b1010_0110, 8, 2), 0b1010_0110);
+}
+
+#[test]
+fn test_u8_make_element_rotate() {
+	assert_eq!(
+		u8::make_element_rotate_left(0b1010_0110, 0, 8, 0),
+		0b1010_0110
+	);
+	assert_eq!(
+		u8::make_element_rotate_left(0b1010_0110, 0, 8, 1),
+		0b0100_1101
+	);
+	assert_eq!(
+		u8::make_element_rotate_left(0b1010_0110, 0, 8, 9),
+		0b0100_1101
+	);
+	assert_eq!(
+		u8::make_element_rotate_left(0b1010_0110, 2, 6, 1),
+		0b1000_1110
+	);
+	assert_eq!(
+		u8::make_element_rotate_left(0b1010_0110, 4, 4, 1),
+		0b1010_0110
+	);
+	assert_eq!(
+		u8::make_element_rotate_right(0b1010_0110, 0, 8, 1),
+		0b0101_0011
+	);
+	assert_eq!(
+		u8::make_element_rotate_right(0b1010_0110, 0, 8, 16),
+		0b1010_0110
+	);
+	assert_eq!(
+		u8::make_element_rotate_right(0b1010_0110, 2, 6, 1),
+		0b1011_0010
+	);
+	assert_eq!(
+		u8::make_element_rotate_right(0b1010_0110, 5, 8, 2),
+		0b1010_0101
+	);
+}
+
+#[test]
+fn test_u8_slice_shift_from() {
+	assert_eq!(u8_slice_shl_from([0x12, 0x34], 0, 4), [0x23, 0x40]);
+	assert_eq!(u8_slice_shl_from([0x12, 0x34], 0, 12), [0x40, 0x00]);
+	assert_eq!(u8_slice_shl_from([0x12, 0x34], 0, 16), [0x00, 0x00]);
+	assert_eq!(u8_slice_shl_from([0x12, 0x34], 4, 4), [0x13, 0x40]);
+	assert_eq!(u8_slice_shl_from([0x12, 0x34], 4, 1), [0x14, 0x68]);
+	assert_eq!(u8_slice_shl_from([0x12, 0x34], 16, 1), [0x12, 0x34]);
+	assert_eq!(u8_slice_shr_from([0x12, 0x34], 0, 4), [0x01, 0x23]);
+	assert_eq!(u8_slice_shr_from([0x12, 0x34], 0, 12), [0x00, 0x01]);
+	assert_eq!(u8_slice_shr_from([0x12, 0x34], 0, 99), [0x00, 0x00]);
+	assert_eq!(u8_slice_shr_from([0x12, 0x34], 4, 4), [0x10, 0x23]);
+	assert_eq!(u8_slice_shr_from([0x12, 0x34], 4, 1), [0x11, 0x1a]);
+	assert_eq!(u8_slice_shr_from([0x12, 0x34], 16, 1), [0x12, 0x34]);
+}
+
+#[test]
+fn test_u8_slice_rotate() {
+	assert_eq!(u8_slice_rotate_left([0x12, 0x34], 0, 16, 4), [0x23, 0x41]);
+	assert_eq!(u8_slice_rotate_left([0x12, 0x34], 0, 16, 12), [0x41, 0x23]);
+	assert_eq!(u8_slice_rotate_left([0x12, 0x34], 4, 12, 4), [0x13, 0x24]);
+	assert_eq!(u8_slice_rotate_left([0x12, 0x34], 4, 12, 0), [0x12, 0x34]);
+	assert_eq!(u8_slice_rotate_left([0x12, 0x34], 8, 8, 3), [0x12, 0x34]);
+	assert_eq!(u8_slice_rotate_right([0x12, 0x34], 0, 16, 4), [0x41, 0x23]);
+	assert_eq!(u8_slice_rotate_right([0x12, 0x34], 4, 12, 4), [0x13, 0x24]);
+	assert_eq!(u8_slice_rotate_right([0x12, 0x34], 0, 16, 17), [0x09, 0x1a]);
+}
+
+#[test]
+#[should_panic]
+fn test_u8_slice_rotate_out_of_range() {
+	u8_slice_rotate_left([0x12, 0x34], 4, 17, 1);
+}
+
+macro_rules! test_shift_rotate_exhaustive {
+	($name:ident, $mod:ident) => {
+		#[test]
+		fn $name() {
+			use super::int_helpers::$mod as helpers;
+
+			const ELEMENTS: usize = 512 / helpers::ELEMENT_BITS;
+			let mut slice = [0; ELEMENTS];
+			for ndx in 0..512 {
+				helpers::slice_set(&mut slice, ndx, (ndx * 7 + ndx / 5) % 3 == 0);
+			}
+			let bits: [bool; 512] = core::array::from_fn(|ndx| helpers::slice_get(&slice, ndx));
+			let check =
+				|result: &[_], expected: &dyn Fn(usize) -> bool, what: core::fmt::Arguments<'_>| {
+					for ndx in 0..512 {
+						assert_eq!(
+							helpers::slice_get(result, ndx),
+							expected(ndx),
+							"{what}: bit {ndx}"
+						);
+					}
+				};
+
+			for prefix in [0, 1, 7, 8, 9, 63, 64, 65, 127, 128, 129, 300, 511, 512] {
+				for count in (0..=300).chain([511, 512, 513, 1000, usize::MAX]) {
+					let mut shl = slice;
+					helpers::slice_shl_from(&mut shl, prefix, count);
+					check(
+						&shl,
+						&|ndx| match ndx.checked_add(count) {
+							_ if ndx < prefix => bits[ndx],
+							Some(src) if src < 512 => bits[src],
+							_ => false,
+						},
+						format_args!("shl_from({prefix}, {count})"),
+					);
+					let mut shr = slice;
+					helpers::slice_shr_from(&mut shr, prefix, count);
+					check(
+						&shr,
+						&|ndx| match ndx.checked_sub(count) {
+							_ if ndx < prefix => bits[ndx],
+							Some(src) if src >= prefix => bits[src],
+							_ => false,
+						},
+						format_args!("shr_from({prefix}, {count})"),
+					);
+				}
+			}
+
+			for (start, end) in [
+				(0, 512),
+				(0, 1),
+				(3, 200),
+				(64, 129),
+				(100, 101),
+				(7, 7),
+				(250, 511),
+			] {
+				let len = end - start;
+				for count in (0..=len + 2).chain([usize::MAX]) {
+					let rotated = |ndx: usize, left: bool| {
+						if ndx < start || ndx >= end {
+							return bits[ndx];
+						}
+						let shift = count % len;
+						let shift = if left { shift } else { len - shift };
+						bits[start + (ndx - start + shift) % len]
+					};
+					let mut left = slice;
+					helpers::slice_rotate_left(&mut left, start, end, count);
+					check(
+						&left,
+						&|ndx| rotated(ndx, true),
+						format_args!("rotate_left({start}, {end}, {count})"),
+					);
+					let mut right = slice;
+					helpers::slice_rotate_right(&mut right, start, end, count);
+					check(
+						&right,
+						&|ndx| rotated(ndx, false),
+						format_args!("rotate_right({start}, {end}, {count})"),
+					);
+				}
+			}
+
+			// single elements
+			let element = slice[0];
+			for prefix in 0..=helpers::ELEMENT_BITS {
+				for count in 0..=helpers::ELEMENT_BITS + 1 {
+					let mut expected = [element];
+					helpers::slice_shl_from(&mut expected, prefix, count);
+					assert_eq!(
+						helpers::make_element_shl_from(element, prefix, count),
+						expected[0]
+					);
+					let mut expected = [element];
+					helpers::slice_shr_from(&mut expected, prefix, count);
+					assert_eq!(
+						helpers::make_element_shr_from(element, prefix, count),
+						expected[0]
+					);
+					let mut expected = [element];
+					helpers::slice_rotate_left(&mut expected, prefix, helpers::ELEMENT_BITS, count);
+					assert_eq!(
+						helpers::make_element_rotate_left(
+							element,
+							prefix,
+							helpers::ELEMENT_BITS,
+							count
+						),
+						expected[0]
+					);
+					let mut expected = [element];
+					helpers::slice_rotate_right(&mut expected, 0, prefix, count);
+					assert_eq!(
+						helpers::make_element_rotate_right(element, 0, prefix, count),
+						expected[0]
+					);
+				}
+			}
+		}
+	};
+}
+
+test_shift_rotate_exhaustive!(test_u8_shift_rotate_exhaustive, u8);
+test_shift_rotate_exhaustive!(test_u16_shift_rotate_exhaustive, u16);
+test_shift_rotate_exhaustive!(test_u32_shift_rotate_exhaustive, u32);
+test_shift_rotate_exhaustive!(test_u64_shift_rotate_exhaustive, u64);
+test_shift_rotate_exhaustive!(test_u128_shift_rotate_exhaustive, u128);
+
+macro_rules! test_extract_insert_exhaustive {
+	($name:ident, $mod:ident, $t:ty) => {
+		#[test]
+		fn $name() {
+			use super::int_helpers::$mod as helpers;
+
+			const ELEMENTS: usize = 256 / helpers::ELEMENT_BITS;
+			// some irregular pattern
+			let mut slice: [$t; ELEMENTS] = [0; ELEMENTS];
+			for ndx in 0..256 {
+				helpers::slice_set(&mut slice, ndx, (ndx * 7 + ndx / 5) % 3 == 0);
+			}
+			for offset in 0..=256 {
+				for width in 0..=(256 - offset).min(128) {
+					let value = helpers::slice_extract(&slice, offset, width);
+					let expected = (offset..offset + width).fold(0u128, |value, ndx| {
+						(value << 1) | helpers::slice_get(&slice, ndx) as u128
+					});
+					assert_eq!(value, expected, "extract({offset}, {width})");
+					if offset + width <= helpers::ELEMENT_BITS {
+						assert_eq!(
+							helpers::element_extract(slice[0], offset, width) as u128,
+							expected
+						);
+					}
+
+					// flip all bits in the field
+					let mask = if width == 0 {
+						0
+					} else {
+						u128::MAX >> (128 - width)
+					};
+					let field = !value & mask;
+					let mut modified = slice;
+					helpers::slice_insert(&mut modified, offset, width, field);
+					for ndx in 0..256 {
+						let in_field = ndx >= offset && ndx < offset + width;
+						assert_eq!(
+							helpers::slice_get(&modified, ndx),
+							helpers::slice_get(&slice, ndx) != in_field,
+							"insert({offset}, {width}) bit {ndx}"
+						);
+					}
+					if offset + width <= helpers::ELEMENT_BITS {
+						assert_eq!(
+							helpers::make_element_insert(slice[0], offset, width, field as $t),
+							modified[0]
+						);
+					}
+				}
+			}
+		}
+	};
+}
+
+test_extract_insert_exhaustive!(test_u8_extract_insert_exhaustive, u8, u8);
+test_extract_insert_exhaustive!(test_u16_extract_insert_exhaustive, u16, u16);
+test_extract_insert_exhaustive!(test_u32_extract_insert_exhaustive, u32, u32);
+test_extract_insert_exhaustive!(test_u64_extract_insert_exhaustive, u64, u64);
+test_extract_insert_exhaustive!(test_u128_extract_insert_exhaustive, u128, u128);
+
+#[test]
+fn test_u8_element_add_sub_exhaustive() {
+	for prefix in 0..=8 {
+		let host_bits = 8 - prefix;
+		let modulus = 1u128 << host_bits;
+		for value in 0..=255u8 {
+			let net = value & !(0xffu16 >> prefix) as u8;
+			let host = u128::from(value) % modulus;
+			for n in [0, 1, 2, 3, 7, 100, 255, 256, 1 << 100, u128::MAX] {
+				let sum = host + n % modulus;
+				let expected = (net | (sum % modulus) as u8, n >= modulus || sum >= modulus);
+				assert_eq!(u8::make_element_add(value, prefix, n), expected);
+				let diff = (host + modulus - n % modulus) % modulus;
+				let expected = (net | diff as u8, n >= modulus || n % modulus > host);
+				assert_eq!(u8::make_element_sub(value, prefix, n), expected);
+			}
+			for other in 0..=255u8 {
+				let other_host = u128::from(other) % modulus;
+				assert_eq!(
+					u128::from(u8::element_distance(value, prefix, other)),
+					host.abs_diff(other_host)
+				);
+			}
+		}
+	}
+}
+
+#[test]
+fn test_u128_element_add_sub() {
+	assert_eq!(u128::make_element_add(u128::MAX, 0, 1), (0, true));
+	assert_eq!(
+		u128::make_element_add(u128::MAX - 1, 0, 1),
+		(u128::MAX, false)
+	);
+	assert_eq!(u128::make_element_sub(0, 0, u128::MAX), (1, true));
+	assert_eq!(
+		u128::make_element_sub(1 << 127, 1, 1),
+		((1 << 127) | (u128::MAX >> 1), true)
+	);
+	assert_eq!(u128::element_distance(0, 0, u128::MAX), u128::MAX);
+	assert_eq!(u128::element_distance(1 << 127, 1, 5), 5);
+}
+
 #[test]
 fn test_u8_slice_inc() {
 	// make sure overflow doesn't change the fixed prefix
@@ -571,3 +1212,167 @@ fn test_u8_slice_contains() {
 		&[!0, 0b0111_1111]
 	));
 }
+
+#[cfg(feature = "bigendian")]
+#[test]
+fn test_bits_extract_insert() {
+	use super::traits::BigEndianBitString;
+
+	let mut value = 0xdead_beefu32;
+	assert_eq!(value.bits_extract(4, 12), 0xead);
+	value.bits_insert(4, 12, 0x123);
+	assert_eq!(value, 0xd123_beef);
+
+	let mut header = [0u16; 4];
+	header[..].bits_insert(51, 13, 0x1abc);
+	assert_eq!(header, [0, 0, 0, 0x1abc]);
+	assert_eq!(header[..].bits_extract(51, 13), 0x1abc);
+	assert_eq!(header[..].bits_extract(48, 16), 0x1abc);
+
+	header[..].bits_shl(48);
+	assert_eq!(header, [0x1abc, 0, 0, 0]);
+	header[..].bits_shr_from(4, 8);
+	assert_eq!(header, [0x100a, 0xbc00, 0, 0]);
+	header[..].bits_rotate_right(0, 32, 4);
+	assert_eq!(header, [0x0100, 0xabc0, 0, 0]);
+	let mut value = 0x1234u16;
+	value.bits_rotate_left(0, 16, 4);
+	assert_eq!(value, 0x2341);
+}
+
+/// Bit string implementing only the required methods of
+/// `BigEndianBitString`, to test the default implementations
+#[cfg(feature = "bigendian")]
+struct RequiredOnly([u8; 5]);
+
+#[cfg(feature = "bigendian")]
+impl super::traits::BigEndianBitString for RequiredOnly {
+	fn bits(&self) -> usize {
+		self.0.bits()
+	}
+
+	fn bits_inc(&mut self, prefix: usize) -> bool {
+		self.0.bits_inc(prefix)
+	}
+
+	fn bit_get(&self, ndx: usize) -> bool {
+		self.0.bit_get(ndx)
+	}
+
+	fn bit_set(&mut self, ndx: usize, bit: bool) {
+		self.0.bit_set(ndx, bit)
+	}
+
+	fn bit_flip(&mut self, ndx: usize) {
+		self.0.bit_flip(ndx)
+	}
+
+	fn shared_prefix_len(&self, other: &Self, max_len: usize) -> usize {
+		self.0.shared_prefix_len(&other.0, max_len)
+	}
+
+	fn set_false_from(&mut self, ndx: usize) {
+		self.0.set_false_from(ndx)
+	}
+
+	fn is_false_from(&self, ndx: usize) -> bool {
+		self.0.is_false_from(ndx)
+	}
+
+	fn set_true_from(&mut self, ndx: usize) {
+		self.0.set_true_from(ndx)
+	}
+
+	fn is_true_from(&self, ndx: usize) -> bool {
+		self.0.is_true_from(ndx)
+	}
+
+	fn bits_prefix_of(&self, prefix_len: usize, value: &Self) -> bool {
+		self.0.bits_prefix_of(prefix_len, &value.0)
+	}
+}
+
+#[cfg(feature = "bigendian")]
+const REQUIRED_ONLY_DATA: [u8; 5] = [0xa5, 0x3c, 0x0f, 0xf0, 0x96];
+
+#[cfg(feature = "bigendian")]
+#[test]
+fn test_default_extract_insert() {
+	use super::traits::BigEndianBitString;
+
+	let bits = RequiredOnly(REQUIRED_ONLY_DATA);
+	for offset in 0..=40 {
+		for width in 0..=40 - offset {
+			let expected = u8::slice_extract(&REQUIRED_ONLY_DATA, offset, width);
+			assert_eq!(bits.bits_extract(offset, width), expected);
+
+			let value = !expected & ((1u128 << width) - 1);
+			let mut inserted = RequiredOnly(REQUIRED_ONLY_DATA);
+			inserted.bits_insert(offset, width, value);
+			let mut expected = REQUIRED_ONLY_DATA;
+			u8::slice_insert(&mut expected, offset, width, value);
+			assert_eq!(inserted.0, expected);
+		}
+	}
+}
+
+#[cfg(feature = "bigendian")]
+#[test]
+fn test_default_search() {
+	use super::traits::BigEndianBitString;
+
+	let bits = RequiredOnly(REQUIRED_ONLY_DATA);
+	assert_eq!(bits.bits_count_ones(), REQUIRED_ONLY_DATA.bits_count_ones());
+	for ndx in 0..=41 {
+		let data = &REQUIRED_ONLY_DATA[..];
+		assert_eq!(bits.bits_find_next_set(ndx), data.bits_find_next_set(ndx));
+		assert_eq!(
+			bits.bits_find_next_clear(ndx),
+			data.bits_find_next_clear(ndx)
+		);
+		assert_eq!(
+			bits.bits_leading_zeros_from(ndx),
+			data.bits_leading_zeros_from(ndx)
+		);
+		assert_eq!(
+			bits.bits_leading_ones_from(ndx),
+			data.bits_leading_ones_from(ndx)
+		);
+	}
+}
+
+#[cfg(feature = "bigendian")]
+#[test]
+fn test_default_shift_rotate() {
+	use super::traits::BigEndianBitString;
+
+	for prefix in 0..=41 {
+		for count in [0, 1, 3, 8, 13, 39, 40, 41, 100] {
+			let mut bits = RequiredOnly(REQUIRED_ONLY_DATA);
+			let mut expected = REQUIRED_ONLY_DATA;
+			bits.bits_shl_from(prefix, count);
+			expected.bits_shl_from(prefix, count);
+			assert_eq!(bits.0, expected, "shl {prefix} {count}");
+
+			let mut bits = RequiredOnly(REQUIRED_ONLY_DATA);
+			let mut expected = REQUIRED_ONLY_DATA;
+			bits.bits_shr_from(prefix, count);
+			expected.bits_shr_from(prefix, count);
+			assert_eq!(bits.0, expected, "shr {prefix} {count}");
+
+			let end = (prefix + 17).min(40);
+			let start = prefix.min(end);
+			let mut bits = RequiredOnly(REQUIRED_ONLY_DATA);
+			let mut expected = REQUIRED_ONLY_DATA;
+			bits.bits_rotate_left(start, end, count);
+			expected.bits_rotate_left(start, end, count);
+			assert_eq!(bits.0, expected, "rotate left {start}..{end} {count}");
+
+			let mut bits = RequiredOnly(REQUIRED_ONLY_DATA);
+			let mut expected = REQUIRED_ONLY_DATA;
+			bits.bits_rotate_right(start, end, count);
+			expected.bits_rotate_right(start, end, count);
+			assert_eq!(bits.0, expected, "rotate right {start}..{end} {count}");
+		}
+	}
+}
@@ -54,6 +54,52 @@ macro_rules! impl_big_endian_for {
 			fn bits_prefix_of(&self, prefix: usize, other: &Self) -> bool {
 				$mod::element_contains(*self, prefix, *other)
 			}
+
+			fn bits_count_ones(&self) -> usize {
+				$mod::element_count_ones(*self)
+			}
+
+			fn bits_leading_zeros_from(&self, ndx: usize) -> usize {
+				$mod::element_leading_zeros_from(*self, ndx)
+			}
+
+			fn bits_leading_ones_from(&self, ndx: usize) -> usize {
+				$mod::element_leading_ones_from(*self, ndx)
+			}
+
+			fn bits_find_next_set(&self, ndx: usize) -> Option<usize> {
+				$mod::element_find_next_set(*self, ndx)
+			}
+
+			fn bits_find_next_clear(&self, ndx: usize) -> Option<usize> {
+				$mod::element_find_next_clear(*self, ndx)
+			}
+
+			fn bits_shl_from(&mut self, prefix: usize, count: usize) {
+				$mod::element_shl_from(self, prefix, count)
+			}
+
+			fn bits_shr_from(&mut self, prefix: usize, count: usize) {
+				$mod::element_shr_from(self, prefix, count)
+			}
+
+			fn bits_rotate_left(&mut self, start: usize, end: usize, count: usize) {
+				$mod::element_rotate_left(self, start, end, count)
+			}
+
+			fn bits_rotate_right(&mut self, start: usize, end: usize, count: usize) {
+				$mod::element_rotate_right(self, start, end, count)
+			}
+
+			fn bits_extract(&self, offset: usize, width: usize) -> core::primitive::u128 {
+				$mod::element_extract(*self, offset, width).into()
+			}
+
+			fn bits_insert(&mut self, offset: usize, width: usize, value: core::primitive::u128) {
+				assert!(offset + width <= $mod::ELEMENT_BITS);
+				let field = value.try_into().expect("value doesn't fit into width bits");
+				$mod::element_insert(self, offset, width, field);
+			}
 		}
 
 		impl BigEndianBitString for [$t] {
@@ -100,6 +146,50 @@ macro_rules! impl_big_endian_for {
 			fn bits_prefix_of(&self, prefix: usize, other: &Self) -> bool {
 				$mod::slice_contains(self, prefix, other)
 			}
+
+			fn bits_count_ones(&self) -> usize {
+				$mod::slice_count_ones(self)
+			}
+
+			fn bits_leading_zeros_from(&self, ndx: usize) -> usize {
+				$mod::slice_leading_zeros_from(self, ndx)
+			}
+
+			fn bits_leading_ones_from(&self, ndx: usize) -> usize {
+				$mod::slice_leading_ones_from(self, ndx)
+			}
+
+			fn bits_find_next_set(&self, ndx: usize) -> Option<usize> {
+				$mod::slice_find_next_set(self, ndx)
+			}
+
+			fn bits_find_next_clear(&self, ndx: usize) -> Option<usize> {
+				$mod::slice_find_next_clear(self, ndx)
+			}
+
+			fn bits_shl_from(&mut self, prefix: usize, count: usize) {
+				$mod::slice_shl_from(self, prefix, count)
+			}
+
+			fn bits_shr_from(&mut self, prefix: usize, count: usize) {
+				$mod::slice_shr_from(self, prefix, count)
+			}
+
+			fn bits_rotate_left(&mut self, start: usize, end: usize, count: usize) {
+				$mod::slice_rotate_left(self, start, end, count)
+			}
+
+			fn bits_rotate_right(&mut self, start: usize, end: usize, count: usize) {
+				$mod::slice_rotate_right(self, start, end, count)
+			}
+
+			fn bits_extract(&self, offset: usize, width: usize) -> core::primitive::u128 {
+				$mod::slice_extract(self, offset, width)
+			}
+
+			fn bits_insert(&mut self, offset: usize, width: usize, value: core::primitive::u128) {
+				$mod::slice_insert(self, offset, width, value)
+			}
 		}
 	};
 }
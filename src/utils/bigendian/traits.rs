@@ -59,4 +59,142 @@ pub trait BigEndianBitString {
 	/// check whether another bit string `value` shares the first
 	/// `prefix_len` bits with `self`
 	fn bits_prefix_of(&self, prefix_len: usize, value: &Self) -> bool;
+
+	/// Number of bits set to `true` (`1`).
+	fn bits_count_ones(&self) -> usize {
+		(0..self.bits()).filter(|&ndx| self.bit_get(ndx)).count()
+	}
+
+	/// Number of consecutive bits set to `false` (`0`) starting at `ndx`.
+	///
+	/// Returns 0 if `ndx >= self.bits()`.
+	fn bits_leading_zeros_from(&self, ndx: usize) -> usize {
+		let end = self.bits_find_next_set(ndx).unwrap_or(self.bits());
+		end.saturating_sub(ndx)
+	}
+
+	/// Number of consecutive bits set to `true` (`1`) starting at `ndx`.
+	///
+	/// Returns 0 if `ndx >= self.bits()`.
+	fn bits_leading_ones_from(&self, ndx: usize) -> usize {
+		let end = self.bits_find_next_clear(ndx).unwrap_or(self.bits());
+		end.saturating_sub(ndx)
+	}
+
+	/// Index of the first bit set to `true` (`1`) in [ndx..], or `None`.
+	fn bits_find_next_set(&self, ndx: usize) -> Option<usize> {
+		(ndx..self.bits()).find(|&ndx| self.bit_get(ndx))
+	}
+
+	/// Index of the first bit set to `false` (`0`) in [ndx..], or `None`.
+	fn bits_find_next_clear(&self, ndx: usize) -> Option<usize> {
+		(ndx..self.bits()).find(|&ndx| !self.bit_get(ndx))
+	}
+
+	/// Shift all bits by `count` bits towards the start (like `<<` on
+	/// integers), filling with `false` (`0`).
+	fn bits_shl(&mut self, count: usize) {
+		self.bits_shl_from(0, count);
+	}
+
+	/// Shift all bits by `count` bits towards the end (like `>>` on
+	/// unsigned integers), filling with `false` (`0`).
+	fn bits_shr(&mut self, count: usize) {
+		self.bits_shr_from(0, count);
+	}
+
+	/// Shift bits [prefix..] by `count` bits towards the start; the
+	/// first `prefix` bits are not modified, bits at the end are filled
+	/// with `false` (`0`).
+	///
+	/// Doesn't do anything if `prefix >= self.bits()`.
+	fn bits_shl_from(&mut self, prefix: usize, count: usize) {
+		let bits = self.bits();
+		for ndx in prefix..bits {
+			let bit = count < bits - ndx && self.bit_get(ndx + count);
+			self.bit_set(ndx, bit);
+		}
+	}
+
+	/// Shift bits [prefix..] by `count` bits towards the end; the first
+	/// `prefix` bits are not modified, bits after the prefix are filled
+	/// with `false` (`0`).
+	///
+	/// Doesn't do anything if `prefix >= self.bits()`.
+	fn bits_shr_from(&mut self, prefix: usize, count: usize) {
+		for ndx in (prefix..self.bits()).rev() {
+			let bit = count <= ndx - prefix && self.bit_get(ndx - count);
+			self.bit_set(ndx, bit);
+		}
+	}
+
+	/// Rotate bits [start..end] by `count` bits towards the start.
+	///
+	/// # Panics
+	///
+	/// Panics if `start > end` or `end > self.bits()`.
+	fn bits_rotate_left(&mut self, start: usize, end: usize, count: usize) {
+		assert!(start <= end && end <= self.bits());
+		let len = end - start;
+		if len == 0 || count % len == 0 {
+			return;
+		}
+		let mid = start + count % len;
+		reverse_range(self, start, mid);
+		reverse_range(self, mid, end);
+		reverse_range(self, start, end);
+	}
+
+	/// Rotate bits [start..end] by `count` bits towards the end.
+	///
+	/// # Panics
+	///
+	/// Panics if `start > end` or `end > self.bits()`.
+	fn bits_rotate_right(&mut self, start: usize, end: usize, count: usize) {
+		assert!(start <= end && end <= self.bits());
+		let len = end - start;
+		if len == 0 {
+			return;
+		}
+		self.bits_rotate_left(start, end, len - count % len);
+	}
+
+	/// Get `width` bits starting at bit `offset` as integer (bit
+	/// `offset + width - 1` is the least significant bit).
+	///
+	/// # Panics
+	///
+	/// Panics if `width > 128` or `offset + width > self.bits()`.
+	fn bits_extract(&self, offset: usize, width: usize) -> u128 {
+		assert!(width <= 128);
+		assert!(offset + width <= self.bits());
+		(offset..offset + width).fold(0, |result, ndx| (result << 1) | self.bit_get(ndx) as u128)
+	}
+
+	/// Replace `width` bits starting at bit `offset` with `value`.
+	///
+	/// # Panics
+	///
+	/// Panics if `width > 128`, `offset + width > self.bits()` or if
+	/// `value` doesn't fit into `width` bits.
+	fn bits_insert(&mut self, offset: usize, width: usize, value: u128) {
+		assert!(width <= 128);
+		assert!(width == 128 || 0 == value >> width);
+		assert!(offset + width <= self.bits());
+		for i in 0..width {
+			self.bit_set(offset + i, 0 != (value >> (width - 1 - i)) & 1);
+		}
+	}
+}
+
+/// reverse the order of bits [start..end]
+fn reverse_range<B: BigEndianBitString + ?Sized>(bits: &mut B, start: usize, end: usize) {
+	let (mut low, mut high) = (start, end);
+	while low + 1 < high {
+		high -= 1;
+		let (a, b) = (bits.bit_get(low), bits.bit_get(high));
+		bits.bit_set(low, b);
+		bits.bit_set(high, a);
+		low += 1;
+	}
 }